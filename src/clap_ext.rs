@@ -14,11 +14,45 @@
 
 //! Clap argument parsing extensions.
 
-use clap::ArgMatches;
+use clap::{Arg, ArgAction, ArgMatches};
 use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
+use crate::output::Verbosity;
+
+/// Argument ID used by [`verbose_arg`].
+pub const VERBOSE_ARG_ID: &str = "verbose";
+
+/// Argument ID used by [`quiet_arg`].
+pub const QUIET_ARG_ID: &str = "quiet";
+
+/// Returns a global, repeatable `-v`/`--verbose` flag.
+///
+/// Use with [`ArgMatchesExt::verbosity`].
+#[must_use]
+pub fn verbose_arg() -> Arg {
+    Arg::new(VERBOSE_ARG_ID)
+        .short('v')
+        .long("verbose")
+        .action(ArgAction::Count)
+        .global(true)
+        .help("Increases output verbosity, can be repeated")
+}
+
+/// Returns a global, repeatable `-q`/`--quiet` flag.
+///
+/// Use with [`ArgMatchesExt::verbosity`].
+#[must_use]
+pub fn quiet_arg() -> Arg {
+    Arg::new(QUIET_ARG_ID)
+        .short('q')
+        .long("quiet")
+        .action(ArgAction::Count)
+        .global(true)
+        .help("Decreases output verbosity")
+}
+
 /// Extension trait for `clap::ArgMatches` with convenient accessor methods.
 pub trait ArgMatchesExt {
     /// Gets a required string argument, panics if missing.
@@ -50,6 +84,18 @@ pub trait ArgMatchesExt {
 
     /// Gets a typed argument with a default value.
     fn get_typed_or<T: Clone + Send + Sync + 'static>(&self, name: &str, default: T) -> T;
+
+    /// Derives the verbosity from the [`verbose_arg`] and [`quiet_arg`] occurrence counts.
+    ///
+    /// The default implementation reads both counts with [`get_typed_or`](Self::get_typed_or),
+    /// so both arguments must be defined if that method panics for unknown ones.
+    /// The `ArgMatches` implementation allows either argument to be omitted from the command.
+    fn verbosity(&self) -> Verbosity {
+        Verbosity::from_counts(
+            self.get_typed_or(VERBOSE_ARG_ID, 0),
+            self.get_typed_or(QUIET_ARG_ID, 0),
+        )
+    }
 }
 
 /// Error type for argument parsing failures.
@@ -121,4 +167,16 @@ impl ArgMatchesExt for ArgMatches {
     fn get_typed_or<T: Clone + Send + Sync + 'static>(&self, name: &str, default: T) -> T {
         self.get_typed(name).unwrap_or(default)
     }
+
+    // `get_one` panics for arguments the command does not define, `try_get_one` does not
+    fn verbosity(&self) -> Verbosity {
+        let count = |id: &str| {
+            self.try_get_one::<u8>(id)
+                .ok()
+                .flatten()
+                .copied()
+                .unwrap_or(0)
+        };
+        Verbosity::from_counts(count(VERBOSE_ARG_ID), count(QUIET_ARG_ID))
+    }
 }
@@ -0,0 +1,314 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Console output over arbitrary writers.
+//!
+//! [`Console`] is what the `print_*` functions use under the hood.
+//! Command handlers can accept a `Console` to write to a file or an in-memory buffer,
+//! which makes them easy to unit test.
+
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Stderr, Stdout, Write};
+
+use crate::output::{
+    ColorChoice, MessageLevel, OutputStream, StreamRouting, Verbosity, color_choice,
+    stream_routing, verbosity,
+};
+use crate::theme::{LevelStyle, Theme, theme};
+
+/// Writes colored, prefixed messages to a pair of output and error sinks.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{ColorChoice, Console};
+///
+/// let mut console = Console::new(Vec::new(), Vec::new()).with_color_choice(ColorChoice::Never);
+/// console.success("Created a queue").unwrap();
+/// console.error("Failed to delete a queue").unwrap();
+///
+/// let (out, err) = console.into_parts();
+/// assert_eq!(String::from_utf8(out).unwrap(), "✓ Created a queue\n");
+/// assert_eq!(String::from_utf8(err).unwrap(), "✗ Failed to delete a queue\n");
+/// ```
+#[derive(Debug)]
+pub struct Console<O = Stdout, E = Stderr> {
+    out: O,
+    err: E,
+    color_choice: Option<ColorChoice>,
+    verbosity: Option<Verbosity>,
+    theme: Option<Theme>,
+    stream_routing: Option<StreamRouting>,
+    out_is_terminal: bool,
+    err_is_terminal: bool,
+}
+
+impl Console<Stdout, Stderr> {
+    /// Creates a console that writes to the process's stdout and stderr.
+    ///
+    /// Colors are decided per stream, based on whether it is a terminal.
+    #[must_use]
+    pub fn stdio() -> Self {
+        let out = io::stdout();
+        let err = io::stderr();
+        let out_is_terminal = out.is_terminal();
+        let err_is_terminal = err.is_terminal();
+        Self {
+            out,
+            err,
+            color_choice: None,
+            verbosity: None,
+            theme: None,
+            stream_routing: None,
+            out_is_terminal,
+            err_is_terminal,
+        }
+    }
+}
+
+impl Default for Console<Stdout, Stderr> {
+    fn default() -> Self {
+        Self::stdio()
+    }
+}
+
+impl<O: Write, E: Write> Console<O, E> {
+    /// Creates a console that writes to the given sinks.
+    ///
+    /// The sinks are not assumed to be terminals, so with `ColorChoice::Auto`
+    /// colors are only used when forced by the environment.
+    #[must_use]
+    pub fn new(out: O, err: E) -> Self {
+        Self {
+            out,
+            err,
+            color_choice: None,
+            verbosity: None,
+            theme: None,
+            stream_routing: None,
+            out_is_terminal: false,
+            err_is_terminal: false,
+        }
+    }
+
+    /// Sets the color choice for this console.
+    ///
+    /// By default, a console follows the process-wide [`color_choice`].
+    #[must_use]
+    pub fn with_color_choice(mut self, choice: ColorChoice) -> Self {
+        self.color_choice = Some(choice);
+        self
+    }
+
+    /// Sets the verbosity for this console.
+    ///
+    /// By default, a console follows the process-wide [`verbosity`].
+    #[must_use]
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = Some(verbosity);
+        self
+    }
+
+    /// Returns the verbosity this console uses.
+    #[must_use]
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity.unwrap_or_else(verbosity)
+    }
+
+    /// Sets the theme for this console.
+    ///
+    /// By default, a console follows the process-wide [`theme`].
+    #[must_use]
+    pub fn with_theme(mut self, theme: impl Into<Theme>) -> Self {
+        self.theme = Some(theme.into());
+        self
+    }
+
+    /// Returns the theme this console uses.
+    #[must_use]
+    pub fn theme(&self) -> Theme {
+        self.theme.clone().unwrap_or_else(theme)
+    }
+
+    /// Sets the stream routing for this console.
+    ///
+    /// By default, a console follows the process-wide [`stream_routing`].
+    #[must_use]
+    pub fn with_stream_routing(mut self, routing: StreamRouting) -> Self {
+        self.stream_routing = Some(routing);
+        self
+    }
+
+    /// Returns the stream routing this console uses.
+    #[must_use]
+    pub fn stream_routing(&self) -> StreamRouting {
+        self.stream_routing.unwrap_or_else(stream_routing)
+    }
+
+    /// Returns whether colors are used for the output sink.
+    #[must_use]
+    pub fn colorize_out(&self) -> bool {
+        self.effective_color_choice().resolve(self.out_is_terminal)
+    }
+
+    /// Returns whether colors are used for the error sink.
+    #[must_use]
+    pub fn colorize_err(&self) -> bool {
+        self.effective_color_choice().resolve(self.err_is_terminal)
+    }
+
+    /// Writes a success message with the success symbol (a green checkmark by default).
+    ///
+    /// Goes to the output sink by default. Suppressed at the `Quiet` verbosity.
+    pub fn success(&mut self, message: impl Display) -> io::Result<()> {
+        if !self.verbosity().allows(Verbosity::Normal) {
+            return Ok(());
+        }
+        let level = self.theme().success;
+        self.emit(MessageLevel::Success, |colorize| {
+            prefixed(&level, message, colorize)
+        })
+    }
+
+    /// Writes an error message with the error symbol (a red X by default).
+    ///
+    /// Goes to the error sink by default.
+    pub fn error(&mut self, message: impl Display) -> io::Result<()> {
+        let level = self.theme().error;
+        self.emit(MessageLevel::Error, |colorize| {
+            prefixed(&level, message, colorize)
+        })
+    }
+
+    /// Writes a warning message with the warning symbol (a yellow exclamation mark by default).
+    ///
+    /// Goes to the output sink by default.
+    pub fn warning(&mut self, message: impl Display) -> io::Result<()> {
+        let level = self.theme().warning;
+        self.emit(MessageLevel::Warning, |colorize| {
+            prefixed(&level, message, colorize)
+        })
+    }
+
+    /// Writes an info message with the info symbol (a blue arrow by default).
+    ///
+    /// Goes to the output sink by default. Suppressed at the `Quiet` verbosity.
+    pub fn info(&mut self, message: impl Display) -> io::Result<()> {
+        if !self.verbosity().allows(Verbosity::Normal) {
+            return Ok(());
+        }
+        let level = self.theme().info;
+        self.emit(MessageLevel::Info, |colorize| {
+            prefixed(&level, message, colorize)
+        })
+    }
+
+    /// Writes a dimmed/muted message.
+    ///
+    /// Goes to the output sink by default. Suppressed at the `Quiet` verbosity.
+    pub fn dimmed(&mut self, message: impl Display) -> io::Result<()> {
+        if !self.verbosity().allows(Verbosity::Normal) {
+            return Ok(());
+        }
+        let level = self.theme().dimmed;
+        self.emit(MessageLevel::Dimmed, |colorize| {
+            styled(&level, message, colorize)
+        })
+    }
+
+    /// Writes an info message if the verbosity is `Verbose` or above.
+    pub fn verbose(&mut self, message: impl Display) -> io::Result<()> {
+        if !self.verbosity().allows(Verbosity::Verbose) {
+            return Ok(());
+        }
+        let level = self.theme().info;
+        self.emit(MessageLevel::Verbose, |colorize| {
+            prefixed(&level, message, colorize)
+        })
+    }
+
+    /// Writes a dimmed message if the verbosity is `Debug` or above.
+    pub fn debug(&mut self, message: impl Display) -> io::Result<()> {
+        if !self.verbosity().allows(Verbosity::Debug) {
+            return Ok(());
+        }
+        let level = self.theme().dimmed;
+        self.emit(MessageLevel::Debug, |colorize| {
+            styled(&level, message, colorize)
+        })
+    }
+
+    /// Writes a dimmed message if the verbosity is `Trace`.
+    pub fn trace(&mut self, message: impl Display) -> io::Result<()> {
+        if !self.verbosity().allows(Verbosity::Trace) {
+            return Ok(());
+        }
+        let level = self.theme().dimmed;
+        self.emit(MessageLevel::Trace, |colorize| {
+            styled(&level, message, colorize)
+        })
+    }
+
+    /// Returns a mutable reference to the output sink, e.g. for printing tables.
+    pub fn out(&mut self) -> &mut O {
+        &mut self.out
+    }
+
+    /// Returns a mutable reference to the error sink.
+    pub fn err(&mut self) -> &mut E {
+        &mut self.err
+    }
+
+    /// Consumes the console, returning the output and error sinks.
+    pub fn into_parts(self) -> (O, E) {
+        (self.out, self.err)
+    }
+
+    // Colors are decided by the sink the message is routed to,
+    // so a warning on a piped stdout but a terminal stderr is still colored
+    fn emit(&mut self, level: MessageLevel, render: impl FnOnce(bool) -> String) -> io::Result<()> {
+        match self.stream_routing().stream_for(level) {
+            OutputStream::Stdout => {
+                let line = render(self.colorize_out());
+                writeln!(self.out, "{}", line)
+            }
+            OutputStream::Stderr => {
+                let line = render(self.colorize_err());
+                writeln!(self.err, "{}", line)
+            }
+        }
+    }
+
+    fn effective_color_choice(&self) -> ColorChoice {
+        self.color_choice.unwrap_or_else(color_choice)
+    }
+}
+
+fn prefixed(level: &LevelStyle, message: impl Display, colorize: bool) -> String {
+    if level.symbol.is_empty() {
+        message.to_string()
+    } else if colorize {
+        format!("{} {}", level.style.paint(&level.symbol), message)
+    } else {
+        format!("{} {}", level.symbol, message)
+    }
+}
+
+fn styled(level: &LevelStyle, message: impl Display, colorize: bool) -> String {
+    if colorize {
+        prefixed(level, level.style.paint(message), colorize)
+    } else {
+        prefixed(level, message, colorize)
+    }
+}
@@ -0,0 +1,265 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Filtering table records with small expressions, e.g. from repeated `--filter` arguments.
+//!
+//! An expression is a column header, an operator and a value:
+//!
+//! - `state=running`, `state!=running`: equality and inequality, numeric when both sides are numbers,
+//!   exact otherwise
+//! - `messages>1000`, `>=`, `<`, `<=`: comparisons, numeric when both sides are numbers,
+//!   natural (like sorting) otherwise
+//!
+//! Values made of digits and dots only, such as `3.10`, are versions when both sides are:
+//! `version=3.10` does not match `3.1` and `version>3.9` matches `3.12`.
+//! - `name~^orders\.`, `name!~tmp`: regular expression (or substring) match and non-match
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::sorting::{looks_like_version, natural_cmp, parse_number};
+use crate::tables::{TableError, header_eq};
+
+/// A filter expression operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    /// `=`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `~`, a regular expression match
+    Matches,
+    /// `!~`, a regular expression non-match
+    NotMatches,
+}
+
+impl FilterOp {
+    // Two-character operators come first so that `!=` is not read as `!` followed by `=`
+    const ALL: [(&'static str, FilterOp); 8] = [
+        ("!=", FilterOp::Ne),
+        (">=", FilterOp::Ge),
+        ("<=", FilterOp::Le),
+        ("!~", FilterOp::NotMatches),
+        ("=", FilterOp::Eq),
+        (">", FilterOp::Gt),
+        ("<", FilterOp::Lt),
+        ("~", FilterOp::Matches),
+    ];
+
+    /// Returns the operator as written in expressions.
+    pub fn as_str(self) -> &'static str {
+        Self::ALL
+            .iter()
+            .find(|(_, op)| *op == self)
+            .map_or("", |(token, _)| token)
+    }
+}
+
+/// A parsed filter expression such as `messages>1000`.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{Filter, FilterOp};
+///
+/// let filter: Filter = "messages > 1000".parse().unwrap();
+/// assert_eq!(filter.column(), "messages");
+/// assert_eq!(filter.op(), FilterOp::Gt);
+/// assert!(filter.matches("1,024"));
+/// assert!(!filter.matches("7"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Filter {
+    column: String,
+    op: FilterOp,
+    value: String,
+    regex: Option<Regex>,
+}
+
+impl Filter {
+    /// Parses a filter expression.
+    ///
+    /// Whitespace around the column and the value is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TableError::InvalidFilter`] if the expression has no operator,
+    /// no column, or an invalid regular expression.
+    pub fn parse(expression: &str) -> Result<Self, TableError> {
+        let invalid = |reason: String| TableError::InvalidFilter {
+            expression: expression.to_string(),
+            reason,
+        };
+
+        let start = expression
+            .find(['=', '!', '<', '>', '~'])
+            .ok_or_else(|| invalid("expected an operator: =, !=, >, >=, <, <=, ~ or !~".into()))?;
+        let rest = &expression[start..];
+        let (token, op) = FilterOp::ALL
+            .iter()
+            .find(|(token, _)| rest.starts_with(token))
+            .ok_or_else(|| invalid(format!("unknown operator at '{}'", rest)))?;
+
+        let column = expression[..start].trim();
+        if column.is_empty() {
+            return Err(invalid("expected a column name before the operator".into()));
+        }
+        let value = rest[token.len()..].trim();
+
+        let regex = match op {
+            FilterOp::Matches | FilterOp::NotMatches => {
+                Some(Regex::new(value).map_err(|e| invalid(e.to_string()))?)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            column: column.to_lowercase(),
+            op: *op,
+            value: value.to_string(),
+            regex,
+        })
+    }
+
+    /// Returns the column header this filter applies to, lowercased.
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Returns the operator.
+    pub fn op(&self) -> FilterOp {
+        self.op
+    }
+
+    /// Returns the value the column is compared with.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns true if a cell value satisfies this filter.
+    pub fn matches(&self, cell: &str) -> bool {
+        let cell = cell.trim();
+        match self.op {
+            // Values that are not numbers are only equal if they are identical
+            FilterOp::Eq => value_cmp(cell, &self.value).is_eq(),
+            FilterOp::Ne => value_cmp(cell, &self.value).is_ne(),
+            FilterOp::Gt => value_cmp(cell, &self.value) == Ordering::Greater,
+            FilterOp::Ge => value_cmp(cell, &self.value) != Ordering::Less,
+            FilterOp::Lt => value_cmp(cell, &self.value) == Ordering::Less,
+            FilterOp::Le => value_cmp(cell, &self.value) != Ordering::Greater,
+            FilterOp::Matches => self.regex.as_ref().is_some_and(|re| re.is_match(cell)),
+            FilterOp::NotMatches => !self.regex.as_ref().is_some_and(|re| re.is_match(cell)),
+        }
+    }
+}
+
+impl FromStr for Filter {
+    type Err = TableError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parses a list of filter expressions, e.g. the values of a repeated `--filter` argument.
+pub fn parse_filters<S: AsRef<str>>(expressions: &[S]) -> Result<Vec<Filter>, TableError> {
+    expressions
+        .iter()
+        .map(|expression| Filter::parse(expression.as_ref()))
+        .collect()
+}
+
+/// Keeps only the records that satisfy all filters, comparing their rendered `Tabled` fields.
+///
+/// Filter columns are matched case-insensitively against `Tabled::headers()`.
+///
+/// # Errors
+///
+/// Returns [`TableError::UnknownFilterColumn`] if a filter column does not match any header.
+/// The records are left untouched in that case.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{filter_records, parse_filters};
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Queue {
+///     name: &'static str,
+///     state: &'static str,
+///     messages: u64,
+/// }
+///
+/// let mut queues = vec![
+///     Queue { name: "orders.eu", state: "running", messages: 5000 },
+///     Queue { name: "orders.us", state: "running", messages: 10 },
+///     Queue { name: "orders.ap", state: "crashed", messages: 9000 },
+///     Queue { name: "events", state: "running", messages: 2000 },
+/// ];
+/// let filters = parse_filters(&["state=running", "messages>1000", r"name~^orders\."]).unwrap();
+/// filter_records(&mut queues, &filters).unwrap();
+///
+/// let names: Vec<_> = queues.iter().map(|q| q.name).collect();
+/// assert_eq!(names, ["orders.eu"]);
+/// ```
+pub fn filter_records<T: tabled::Tabled>(
+    data: &mut Vec<T>,
+    filters: &[Filter],
+) -> Result<(), TableError> {
+    let headers: Vec<String> = T::headers().into_iter().map(|h| h.to_string()).collect();
+    let indices = filters
+        .iter()
+        .map(|filter| {
+            headers
+                .iter()
+                .position(|h| header_eq(h, &filter.column))
+                .ok_or_else(|| TableError::UnknownFilterColumn {
+                    column: filter.column.clone(),
+                    available: headers.clone(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    data.retain(|item| {
+        let fields = item.fields();
+        filters
+            .iter()
+            .zip(&indices)
+            .all(|(filter, &idx)| filter.matches(&fields[idx]))
+    });
+    Ok(())
+}
+
+/// Compares a cell value with a filter value numerically if both are numbers
+/// but not both versions, and naturally otherwise.
+fn value_cmp(a: &str, b: &str) -> Ordering {
+    if looks_like_version(a) && looks_like_version(b) {
+        return natural_cmp(a, b);
+    }
+    match (parse_number(a), parse_number(b)) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        _ => natural_cmp(a, b),
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rendering of records as a table, JSON, YAML, CSV or TSV.
+
+use std::error::Error;
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tabled::Tabled;
+
+use crate::tables::{StyledTable, TableStyle, header_eq};
+
+/// Output formats for listing commands, typically selected with an `--output` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OutputFormat {
+    /// A table in the configured [`TableStyle`] (default).
+    #[default]
+    Table,
+    /// A pretty-printed JSON array.
+    Json,
+    /// A YAML sequence.
+    Yaml,
+    /// Comma-separated values with a header row.
+    Csv,
+    /// Tab-separated values with a header row.
+    Tsv,
+}
+
+#[cfg(feature = "clap")]
+impl clap::ValueEnum for OutputFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Table, Self::Json, Self::Yaml, Self::Csv, Self::Tsv]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(clap::builder::PossibleValue::new(match self {
+            Self::Table => "table",
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+        }))
+    }
+}
+
+/// Error returned when records cannot be serialized.
+#[derive(Debug)]
+pub enum RenderError {
+    Json(serde_json::Error),
+    Yaml(serde_yaml_ng::Error),
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::Json(e) => write!(f, "failed to render JSON: {}", e),
+            RenderError::Yaml(e) => write!(f, "failed to render YAML: {}", e),
+        }
+    }
+}
+
+impl Error for RenderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RenderError::Json(e) => Some(e),
+            RenderError::Yaml(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for RenderError {
+    fn from(e: serde_json::Error) -> Self {
+        RenderError::Json(e)
+    }
+}
+
+impl From<serde_yaml_ng::Error> for RenderError {
+    fn from(e: serde_yaml_ng::Error) -> Self {
+        RenderError::Yaml(e)
+    }
+}
+
+/// Renders records in any of the [`OutputFormat`]s.
+///
+/// Columns are selected the same way for every format: names are matched
+/// case-insensitively against the `Tabled` headers and unknown names are ignored.
+/// For JSON and YAML, a selected header is mapped to the serialized field with
+/// the same name, compared case-insensitively and with spaces treated as underscores.
+/// Fields with a `tabled(rename)` header therefore need a matching `serde(rename)`
+/// to be selected in these formats, e.g. `Queue Name` and `queue_name`.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{OutputFormat, RecordRenderer, parse_columns};
+/// use serde::Serialize;
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled, Serialize)]
+/// struct Queue {
+///     name: String,
+///     messages: u64,
+/// }
+///
+/// let queues = vec![Queue { name: "orders".into(), messages: 12 }];
+/// let csv = RecordRenderer::new(OutputFormat::Csv)
+///     .columns(parse_columns("messages"))
+///     .render(&queues)
+///     .unwrap();
+/// assert_eq!(csv, "messages\n12");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RecordRenderer {
+    format: OutputFormat,
+    style: TableStyle,
+    columns: Option<Vec<String>>,
+}
+
+impl RecordRenderer {
+    /// Creates a renderer for the given format, using the default table style.
+    #[must_use]
+    pub fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            style: TableStyle::default(),
+            columns: None,
+        }
+    }
+
+    /// Sets the table style used by the `Table` format.
+    #[must_use]
+    pub fn style(mut self, style: TableStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Limits the output to the given columns, usually produced by [`crate::parse_columns`].
+    #[must_use]
+    pub fn columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Renders the records. The result has no trailing newline.
+    pub fn render<T: Tabled + Serialize>(&self, data: &[T]) -> Result<String, RenderError> {
+        match self.format {
+            OutputFormat::Table => Ok(self.render_table(data)),
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(&self.to_values(data)?)?),
+            OutputFormat::Yaml => {
+                let yaml = serde_yaml_ng::to_string(&self.to_values(data)?)?;
+                Ok(yaml.trim_end().to_string())
+            }
+            OutputFormat::Csv => Ok(self.render_delimited(data, ',', escape_csv)),
+            OutputFormat::Tsv => Ok(self.render_delimited(data, '\t', escape_tsv)),
+        }
+    }
+
+    fn render_table<T: Tabled>(&self, data: &[T]) -> String {
+        let mut table = StyledTable::new().style(self.style);
+        if let Some(columns) = &self.columns {
+            table = table.columns(columns.clone());
+        }
+        table.build(data.iter().collect()).to_string()
+    }
+
+    fn to_values<T: Tabled + Serialize>(&self, data: &[T]) -> Result<Vec<Value>, RenderError> {
+        let selected = self.selected_indices::<T>();
+        data.iter()
+            .map(|item| {
+                let value = serde_json::to_value(item)?;
+                Ok(match (&selected, value) {
+                    (Some(indices), Value::Object(map)) => {
+                        Value::Object(select_fields::<T>(map, indices))
+                    }
+                    (_, value) => value,
+                })
+            })
+            .collect()
+    }
+
+    fn render_delimited<T: Tabled>(
+        &self,
+        data: &[T],
+        delimiter: char,
+        escape: fn(&str, char) -> String,
+    ) -> String {
+        let headers: Vec<String> = T::headers().into_iter().map(|h| h.to_string()).collect();
+        let indices = self
+            .selected_indices::<T>()
+            .unwrap_or_else(|| (0..headers.len()).collect());
+        let separator = delimiter.to_string();
+
+        let mut lines = Vec::with_capacity(data.len() + 1);
+        lines.push(
+            indices
+                .iter()
+                .map(|&idx| escape(&headers[idx], delimiter))
+                .collect::<Vec<_>>()
+                .join(&separator),
+        );
+        for item in data {
+            let fields = item.fields();
+            lines.push(
+                indices
+                    .iter()
+                    .map(|&idx| escape(&fields[idx], delimiter))
+                    .collect::<Vec<_>>()
+                    .join(&separator),
+            );
+        }
+        lines.join("\n")
+    }
+
+    fn selected_indices<T: Tabled>(&self) -> Option<Vec<usize>> {
+        let columns = self.columns.as_ref()?;
+        let headers = T::headers();
+        Some(
+            columns
+                .iter()
+                .filter_map(|col| headers.iter().position(|h| header_eq(h, col)))
+                .collect(),
+        )
+    }
+}
+
+fn select_fields<T: Tabled>(map: Map<String, Value>, indices: &[usize]) -> Map<String, Value> {
+    let headers: Vec<String> = T::headers().iter().map(|h| field_name(h)).collect();
+
+    let mut selected = Map::new();
+    for &idx in indices {
+        if let Some((key, value)) = map.iter().find(|(key, _)| field_name(key) == headers[idx]) {
+            selected.insert(key.clone(), value.clone());
+        }
+    }
+    selected
+}
+
+/// Normalizes a header or a serialized field name for matching one against the other.
+fn field_name(name: &str) -> String {
+    name.to_lowercase().replace(' ', "_")
+}
+
+fn escape_csv(value: &str, delimiter: char) -> String {
+    if value.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn escape_tsv(value: &str, _delimiter: char) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
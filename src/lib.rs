@@ -17,30 +17,45 @@
 //! This crate provides:
 //!
 //! - Colored console output helpers (success, error, warning, info)
-//! - String truncation for display
-//! - Table styling utilities (requires `tables` feature)
+//! - A writer-generic `Console` for testable output
+//! - Themeable output symbols and colors, including an ASCII-only theme
+//! - String truncation and text wrapping for display
+//! - Table styling and sorting utilities (requires `tables` feature)
+//! - Filtering table records with expressions (requires `filtering` feature)
 //! - Clap argument helpers (requires `clap` feature)
 //! - Shell completion generation (requires `completions` feature)
 //! - Progress reporting (requires `progress` feature)
+//! - Rendering records as a table, JSON, YAML, CSV or TSV (requires `formats` feature)
 //!
 //! # Features
 //!
 //! - `tables` - Enables table styling with `tabled`
+//! - `filtering` - Enables row filtering expressions, with `regex` for pattern matches
 //! - `clap` - Enables clap argument helper extensions
 //! - `completions` - Enables shell completion generation
 //! - `progress` - Enables progress reporting utilities
 //! - `errors` - Enables exit code mapping with `sysexits`
+//! - `formats` - Enables `OutputFormat` rendering with `serde_json` and `serde_yaml_ng`
 //! - `full` - Enables all features
 
 #[cfg(feature = "errors")]
 mod errors;
 
+mod console;
 mod output;
+mod theme;
 mod truncate;
+mod wrap;
 
 #[cfg(feature = "tables")]
 mod tables;
 
+#[cfg(feature = "tables")]
+mod sorting;
+
+#[cfg(feature = "filtering")]
+mod filtering;
+
 #[cfg(feature = "clap")]
 mod clap_ext;
 
@@ -50,12 +65,24 @@ mod completions;
 #[cfg(feature = "progress")]
 mod progress;
 
+#[cfg(feature = "formats")]
+mod formats;
+
+pub use console::*;
 pub use output::*;
+pub use theme::*;
 pub use truncate::*;
+pub use wrap::*;
 
 #[cfg(feature = "tables")]
 pub use tables::*;
 
+#[cfg(feature = "tables")]
+pub use sorting::*;
+
+#[cfg(feature = "filtering")]
+pub use filtering::*;
+
 #[cfg(feature = "clap")]
 pub use clap_ext::*;
 
@@ -65,6 +92,9 @@ pub use completions::*;
 #[cfg(feature = "progress")]
 pub use progress::*;
 
+#[cfg(feature = "formats")]
+pub use formats::*;
+
 #[cfg(feature = "errors")]
 pub use errors::*;
 
@@ -15,134 +15,449 @@
 //! Colored console output utilities.
 //!
 //! Provides consistent, colored output for CLI applications.
-//! Respects the `NO_COLOR` environment variable and detects non-TTY output.
+//! Respects the process-wide [`ColorChoice`], the `NO_COLOR`, `CLICOLOR`,
+//! `CLICOLOR_FORCE`, `FORCE_COLOR` and `TERM` environment variables,
+//! and detects non-TTY output.
+//!
+//! The `print_*` functions write to stdout and stderr using a default [`Console`].
 
 use std::env;
 use std::fmt::Display;
 use std::io::IsTerminal;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU8, Ordering};
 
 use owo_colors::OwoColorize;
 
+use crate::console::Console;
+use crate::theme::{TextStyle, ThemeColor, theme};
+
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+static STREAM_ROUTING: RwLock<StreamRouting> = RwLock::new(StreamRouting::new());
+
+/// Controls when colored output is used.
+///
+/// Applications typically set this once at startup from a `--color` flag
+/// using [`set_color_choice`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorChoice {
+    /// Decide based on the environment and terminal detection (default).
+    #[default]
+    Auto,
+    /// Always use colors, even when output is piped or redirected.
+    Always,
+    /// Never use colors.
+    Never,
+}
+
+#[cfg(feature = "clap")]
+impl clap::ValueEnum for ColorChoice {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Auto, Self::Always, Self::Never]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(clap::builder::PossibleValue::new(match self {
+            Self::Auto => "auto",
+            Self::Always => "always",
+            Self::Never => "never",
+        }))
+    }
+}
+
+impl ColorChoice {
+    /// Returns whether colors should be used for a stream with the given terminal status.
+    ///
+    /// `Always` and `Never` ignore both the environment and `is_terminal`.
+    /// `Auto` checks, in order:
+    ///
+    /// 1. `NO_COLOR` (any value) disables colors
+    /// 2. `CLICOLOR_FORCE` or `FORCE_COLOR` (set to anything but `0` or `false`) enables colors
+    /// 3. `TERM=dumb` disables colors
+    /// 4. `CLICOLOR=0` disables colors
+    /// 5. Otherwise, colors are used only if the stream is a terminal
+    #[must_use]
+    pub fn resolve(self, is_terminal: bool) -> bool {
+        self.resolve_with_env(is_terminal, |name| {
+            env::var_os(name).map(|value| value.to_string_lossy().into_owned())
+        })
+    }
+
+    /// Like [`resolve`](Self::resolve), but looks up environment variables with `var`
+    /// instead of reading the process environment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_cli::ColorChoice;
+    ///
+    /// let env = |name: &str| (name == "NO_COLOR").then(|| "1".to_string());
+    /// assert!(!ColorChoice::Auto.resolve_with_env(true, env));
+    /// ```
+    #[must_use]
+    pub fn resolve_with_env(self, is_terminal: bool, var: impl Fn(&str) -> Option<String>) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => env_color_override(var).unwrap_or(is_terminal),
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => ColorChoice::Always,
+            2 => ColorChoice::Never,
+            _ => ColorChoice::Auto,
+        }
+    }
+}
+
+/// Sets the process-wide color choice used by the `print_*` and `format_*` functions.
+pub fn set_color_choice(choice: ColorChoice) {
+    COLOR_CHOICE.store(choice as u8, Ordering::Relaxed);
+}
+
+/// Returns the process-wide color choice.
+#[must_use]
+pub fn color_choice() -> ColorChoice {
+    ColorChoice::from_u8(COLOR_CHOICE.load(Ordering::Relaxed))
+}
+
+fn env_color_override(var: impl Fn(&str) -> Option<String>) -> Option<bool> {
+    let flag_enabled =
+        |name| var(name).is_some_and(|value| !value.is_empty() && value != "0" && value != "false");
+
+    if var("NO_COLOR").is_some() {
+        return Some(false);
+    }
+    if flag_enabled("CLICOLOR_FORCE") || flag_enabled("FORCE_COLOR") {
+        return Some(true);
+    }
+    if var("TERM").is_some_and(|term| term == "dumb") {
+        return Some(false);
+    }
+    if var("CLICOLOR").is_some_and(|value| value == "0") {
+        return Some(false);
+    }
+    None
+}
+
+/// Output verbosity levels, ordered from the least to the most verbose.
+///
+/// Errors and warnings are always printed. `Quiet` suppresses success, info
+/// and dimmed messages; `Verbose`, `Debug` and `Trace` enable the
+/// [`print_verbose`], [`print_debug`] and [`print_trace`] messages, respectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Verbosity {
+    /// Only warnings and errors.
+    Quiet,
+    /// Regular output (default).
+    #[default]
+    Normal,
+    /// Additional details (`-v`).
+    Verbose,
+    /// Debugging output (`-vv`).
+    Debug,
+    /// Everything (`-vvv`).
+    Trace,
+}
+
+impl Verbosity {
+    /// Derives a level from the number of `-v` and `-q` occurrences.
+    ///
+    /// Each `-v` raises the level by one, each `-q` lowers it by one,
+    /// starting at `Normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_cli::Verbosity;
+    ///
+    /// assert_eq!(Verbosity::from_counts(0, 0), Verbosity::Normal);
+    /// assert_eq!(Verbosity::from_counts(2, 0), Verbosity::Debug);
+    /// assert_eq!(Verbosity::from_counts(0, 1), Verbosity::Quiet);
+    /// assert_eq!(Verbosity::from_counts(9, 0), Verbosity::Trace);
+    /// ```
+    #[must_use]
+    pub fn from_counts(verbose: u8, quiet: u8) -> Self {
+        let level = Verbosity::Normal as i16 + i16::from(verbose) - i16::from(quiet);
+        Self::from_u8(level.clamp(0, Verbosity::Trace as i16) as u8)
+    }
+
+    /// Returns whether messages of the `level` verbosity should be printed at this level.
+    #[must_use]
+    pub fn allows(self, level: Verbosity) -> bool {
+        self >= level
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Verbosity::Quiet,
+            2 => Verbosity::Verbose,
+            3 => Verbosity::Debug,
+            4 => Verbosity::Trace,
+            _ => Verbosity::Normal,
+        }
+    }
+}
+
+/// Sets the process-wide verbosity used by the `print_*` functions.
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+/// Returns the process-wide verbosity.
+#[must_use]
+pub fn verbosity() -> Verbosity {
+    Verbosity::from_u8(VERBOSITY.load(Ordering::Relaxed))
+}
+
+/// An output stream a message can be routed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// Message levels, one per `print_*` function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageLevel {
+    Success,
+    Error,
+    Warning,
+    Info,
+    Dimmed,
+    Verbose,
+    Debug,
+    Trace,
+}
+
+/// Decides which stream messages of each level are written to.
+///
+/// The default routing sends errors to stderr and everything else to stdout.
+/// Commands that print machine-readable output (JSON, borderless tables)
+/// can use [`StreamRouting::all_to_stderr`] to keep stdout clean.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{MessageLevel, OutputStream, StreamRouting};
+///
+/// let routing = StreamRouting::new()
+///     .route(MessageLevel::Warning, OutputStream::Stderr)
+///     .route(MessageLevel::Info, OutputStream::Stderr);
+/// assert_eq!(routing.stream_for(MessageLevel::Success), OutputStream::Stdout);
+/// assert_eq!(routing.stream_for(MessageLevel::Warning), OutputStream::Stderr);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamRouting {
+    pub success: OutputStream,
+    pub error: OutputStream,
+    pub warning: OutputStream,
+    pub info: OutputStream,
+    pub dimmed: OutputStream,
+    pub verbose: OutputStream,
+    pub debug: OutputStream,
+    pub trace: OutputStream,
+}
+
+impl StreamRouting {
+    /// The default routing: errors go to stderr, everything else to stdout.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            success: OutputStream::Stdout,
+            error: OutputStream::Stderr,
+            warning: OutputStream::Stdout,
+            info: OutputStream::Stdout,
+            dimmed: OutputStream::Stdout,
+            verbose: OutputStream::Stdout,
+            debug: OutputStream::Stdout,
+            trace: OutputStream::Stdout,
+        }
+    }
+
+    /// Routes messages of every level to stderr, leaving stdout to command output.
+    #[must_use]
+    pub const fn all_to_stderr() -> Self {
+        Self {
+            success: OutputStream::Stderr,
+            error: OutputStream::Stderr,
+            warning: OutputStream::Stderr,
+            info: OutputStream::Stderr,
+            dimmed: OutputStream::Stderr,
+            verbose: OutputStream::Stderr,
+            debug: OutputStream::Stderr,
+            trace: OutputStream::Stderr,
+        }
+    }
+
+    /// Routes messages of the given level to a stream.
+    #[must_use]
+    pub const fn route(mut self, level: MessageLevel, stream: OutputStream) -> Self {
+        match level {
+            MessageLevel::Success => self.success = stream,
+            MessageLevel::Error => self.error = stream,
+            MessageLevel::Warning => self.warning = stream,
+            MessageLevel::Info => self.info = stream,
+            MessageLevel::Dimmed => self.dimmed = stream,
+            MessageLevel::Verbose => self.verbose = stream,
+            MessageLevel::Debug => self.debug = stream,
+            MessageLevel::Trace => self.trace = stream,
+        }
+        self
+    }
+
+    /// Returns the stream messages of the given level are written to.
+    #[must_use]
+    pub const fn stream_for(&self, level: MessageLevel) -> OutputStream {
+        match level {
+            MessageLevel::Success => self.success,
+            MessageLevel::Error => self.error,
+            MessageLevel::Warning => self.warning,
+            MessageLevel::Info => self.info,
+            MessageLevel::Dimmed => self.dimmed,
+            MessageLevel::Verbose => self.verbose,
+            MessageLevel::Debug => self.debug,
+            MessageLevel::Trace => self.trace,
+        }
+    }
+}
+
+impl Default for StreamRouting {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sets the process-wide stream routing used by the `print_*` functions.
+pub fn set_stream_routing(routing: StreamRouting) {
+    let mut current = STREAM_ROUTING.write().unwrap_or_else(|e| e.into_inner());
+    *current = routing;
+}
+
+/// Returns the process-wide stream routing.
+#[must_use]
+pub fn stream_routing() -> StreamRouting {
+    *STREAM_ROUTING.read().unwrap_or_else(|e| e.into_inner())
+}
+
 /// Returns whether colored output should be used.
 ///
-/// Returns `false` if the `NO_COLOR` environment variable is set (any value)
-/// or `stdout` is not a terminal (that is, piped or redirected).
+/// Resolves the process-wide [`ColorChoice`] against `stdout`:
+/// with the default `Auto` choice, returns `false` if the `NO_COLOR`
+/// environment variable is set (any value) or `stdout` is not a terminal
+/// (that is, piped or redirected). See [`ColorChoice::resolve`] for the full set of rules.
 ///
 /// This follows the [NO_COLOR standard](https://no-color.org/).
 #[must_use]
 pub fn should_colorize() -> bool {
-    env::var("NO_COLOR").is_err() && std::io::stdout().is_terminal()
+    color_choice().resolve(std::io::stdout().is_terminal())
 }
 
 /// Returns whether colored output should be used for stderr.
 ///
-/// Returns `false` if the `NO_COLOR` environment variable is set (any value)
-/// or `stdout` is not a terminal (that is, piped or redirected).
+/// Same as [`should_colorize`] but checks whether `stderr` is a terminal.
 #[must_use]
 pub fn should_colorize_stderr() -> bool {
-    env::var("NO_COLOR").is_err() && std::io::stderr().is_terminal()
+    color_choice().resolve(std::io::stderr().is_terminal())
 }
 
-/// Prints a success message with a green checkmark prefix.
+/// Prints a success message with the theme's success symbol (a green checkmark by default).
+///
+/// Goes to stdout unless routed elsewhere with [`set_stream_routing`].
 ///
-/// Respects `NO_COLOR` and terminal detection.
+/// Respects the color choice, `NO_COLOR` and terminal detection.
+/// Suppressed at the `Quiet` verbosity.
 pub fn print_success(message: impl Display) {
-    if should_colorize() {
-        println!("{} {}", "✓".green().bold(), message);
-    } else {
-        println!("✓ {}", message);
-    }
+    let _ = Console::stdio().success(message);
 }
 
-/// Prints an error message to stderr with a red X prefix.
+/// Prints an error message with the theme's error symbol (a red X by default).
+///
+/// Goes to stderr unless routed elsewhere with [`set_stream_routing`].
 ///
-/// Respects `NO_COLOR` and terminal detection.
+/// Respects the color choice, `NO_COLOR` and terminal detection.
 pub fn print_error(message: impl Display) {
-    if should_colorize_stderr() {
-        eprintln!("{} {}", "✗".red().bold(), message);
-    } else {
-        eprintln!("✗ {}", message);
-    }
+    let _ = Console::stdio().error(message);
 }
 
-/// Prints a warning message with a yellow exclamation prefix.
+/// Prints a warning message with the theme's warning symbol (a yellow exclamation mark by default).
+///
+/// Goes to stdout unless routed elsewhere with [`set_stream_routing`].
 ///
-/// Respects `NO_COLOR` and terminal detection.
+/// Respects the color choice, `NO_COLOR` and terminal detection.
 pub fn print_warning(message: impl Display) {
-    if should_colorize() {
-        println!("{} {}", "!".yellow().bold(), message);
-    } else {
-        println!("! {}", message);
-    }
+    let _ = Console::stdio().warning(message);
 }
 
-/// Prints an info message with a blue arrow prefix.
+/// Prints an info message with the theme's info symbol (a blue arrow by default).
+///
+/// Goes to stdout unless routed elsewhere with [`set_stream_routing`].
 ///
-/// Respects `NO_COLOR` and terminal detection.
+/// Respects the color choice, `NO_COLOR` and terminal detection.
+/// Suppressed at the `Quiet` verbosity.
 pub fn print_info(message: impl Display) {
-    if should_colorize() {
-        println!("{} {}", "→".blue().bold(), message);
-    } else {
-        println!("→ {}", message);
-    }
+    let _ = Console::stdio().info(message);
 }
 
 /// Prints a dimmed/muted message.
 ///
-/// Respects `NO_COLOR` and terminal detection.
+/// Respects the color choice, `NO_COLOR` and terminal detection.
+/// Suppressed at the `Quiet` verbosity.
 pub fn print_dimmed(message: impl Display) {
-    if should_colorize() {
-        println!("{}", message.to_string().dimmed());
-    } else {
-        println!("{}", message);
-    }
+    let _ = Console::dimmed(&mut Console::stdio(), message);
+}
+
+/// Prints an info message that is only shown at the `Verbose` verbosity or above.
+pub fn print_verbose(message: impl Display) {
+    let _ = Console::stdio().verbose(message);
+}
+
+/// Prints a dimmed message that is only shown at the `Debug` verbosity or above.
+pub fn print_debug(message: impl Display) {
+    let _ = Console::stdio().debug(message);
 }
 
-/// Formats a value as success (green) if colors are enabled.
+/// Prints a dimmed message that is only shown at the `Trace` verbosity.
+pub fn print_trace(message: impl Display) {
+    let _ = Console::stdio().trace(message);
+}
+
+/// Formats a value with the theme's success color (green by default) if colors are enabled.
 #[must_use]
 pub fn format_success<T: Display>(value: T) -> String {
-    if should_colorize() {
-        format!("{}", value.green())
-    } else {
-        value.to_string()
-    }
+    format_with_color(value, theme().success.style.color)
 }
 
-/// Formats a value as error (red) if colors are enabled.
+/// Formats a value with the theme's error color (red by default) if colors are enabled.
 #[must_use]
 pub fn format_error<T: Display>(value: T) -> String {
-    if should_colorize() {
-        format!("{}", value.red())
-    } else {
-        value.to_string()
-    }
+    format_with_color(value, theme().error.style.color)
 }
 
-/// Formats a value as warning (yellow) if colors are enabled.
+/// Formats a value with the theme's warning color (yellow by default) if colors are enabled.
 #[must_use]
 pub fn format_warning<T: Display>(value: T) -> String {
-    if should_colorize() {
-        format!("{}", value.yellow())
-    } else {
-        value.to_string()
-    }
+    format_with_color(value, theme().warning.style.color)
 }
 
-/// Formats a value as info (blue) if colors are enabled.
+/// Formats a value with the theme's info color (blue by default) if colors are enabled.
 #[must_use]
 pub fn format_info<T: Display>(value: T) -> String {
-    if should_colorize() {
-        format!("{}", value.blue())
-    } else {
-        value.to_string()
-    }
+    format_with_color(value, theme().info.style.color)
 }
 
-/// Formats a value as dimmed/muted if colors are enabled.
+/// Formats a value with the theme's dimmed style if colors are enabled.
 #[must_use]
 pub fn format_dimmed<T: Display>(value: T) -> String {
     if should_colorize() {
-        format!("{}", value.dimmed())
+        theme().dimmed.style.paint(value)
     } else {
         value.to_string()
     }
@@ -157,3 +472,10 @@ pub fn format_bold<T: Display>(value: T) -> String {
         value.to_string()
     }
 }
+
+fn format_with_color<T: Display>(value: T, color: Option<ThemeColor>) -> String {
+    match color {
+        Some(color) if should_colorize() => TextStyle::new().color(color).paint(value),
+        _ => value.to_string(),
+    }
+}
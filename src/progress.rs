@@ -18,6 +18,8 @@ use std::time::Duration;
 
 use indicatif::{ProgressBar, ProgressStyle};
 
+use crate::output::Verbosity;
+
 /// A trait for reporting progress during multi-item operations.
 pub trait ProgressReporter {
     /// Called when starting a batch operation.
@@ -186,6 +188,18 @@ pub fn select_reporter(quiet: bool, non_interactive: bool) -> Box<dyn ProgressRe
     }
 }
 
+/// Selects a progress reporter based on the output verbosity.
+///
+/// `Verbosity::Quiet` selects a [`QuietReporter`], any other level
+/// behaves like [`select_reporter`] with `quiet` set to `false`.
+#[must_use]
+pub fn select_reporter_for_verbosity(
+    verbosity: Verbosity,
+    non_interactive: bool,
+) -> Box<dyn ProgressReporter> {
+    select_reporter(verbosity == Verbosity::Quiet, non_interactive)
+}
+
 /// Default Braille spinner characters.
 pub const BRAILLE_TICK_CHARS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
@@ -0,0 +1,289 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sorting table records by one or more columns, e.g. from a `--sort-by messages:desc,name` argument.
+
+use std::cmp::Ordering;
+
+use crate::tables::{TableError, header_eq};
+
+/// The direction of a [`SortKey`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    /// Smallest first (default).
+    #[default]
+    Ascending,
+    /// Largest first.
+    Descending,
+}
+
+/// A column to sort by, and in which direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    /// The column header, matched case-insensitively.
+    pub column: String,
+    /// The sort direction.
+    pub direction: SortDirection,
+}
+
+impl SortKey {
+    /// Creates an ascending sort key.
+    pub fn ascending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    /// Creates a descending sort key.
+    pub fn descending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Descending,
+        }
+    }
+}
+
+/// Parses a comma-separated sort spec such as `messages:desc,name` into sort keys.
+///
+/// Every key can be followed by `:asc` (the default) or `:desc`. Like [`parse_columns`](crate::parse_columns),
+/// trims whitespace, lowercases column names and filters empty entries.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{SortKey, parse_sort_spec};
+///
+/// let keys = parse_sort_spec("messages:desc, Name").unwrap();
+/// assert_eq!(keys, vec![SortKey::descending("messages"), SortKey::ascending("name")]);
+/// ```
+pub fn parse_sort_spec(spec: &str) -> Result<Vec<SortKey>, TableError> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|entry| {
+            let (column, direction) = match entry.split_once(':') {
+                Some((column, direction)) => (column.trim(), direction.trim()),
+                None => (entry, ""),
+            };
+            let column = column.to_lowercase();
+            let direction = match direction.to_lowercase().as_str() {
+                "" | "asc" | "ascending" => SortDirection::Ascending,
+                "desc" | "descending" => SortDirection::Descending,
+                _ => {
+                    return Err(TableError::InvalidSortDirection {
+                        key: column,
+                        direction: direction.to_string(),
+                    });
+                }
+            };
+            Ok(SortKey { column, direction })
+        })
+        .collect()
+}
+
+/// Sorts records by the given keys, comparing their rendered `Tabled` fields.
+///
+/// Keys are matched case-insensitively against `Tabled::headers()`. Columns where every non-empty
+/// value parses as a number (thousands separators allowed) are compared numerically, with empty
+/// values first, unless every such value is made of digits and dots only. Those columns hold
+/// versions (`3.9`, `3.10`, `3.12`) and, like all other columns, are compared with [`natural_cmp`].
+/// The sort is stable.
+///
+/// # Errors
+///
+/// Returns [`TableError::UnknownSortKey`] if a key does not match any header.
+/// The records are left untouched in that case.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{parse_sort_spec, sort_records};
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Queue {
+///     name: &'static str,
+///     messages: u64,
+/// }
+///
+/// let mut queues = vec![
+///     Queue { name: "queue.10", messages: 5 },
+///     Queue { name: "queue.9", messages: 5 },
+///     Queue { name: "queue.1", messages: 200 },
+/// ];
+/// sort_records(&mut queues, &parse_sort_spec("messages:desc,name").unwrap()).unwrap();
+///
+/// let names: Vec<_> = queues.iter().map(|q| q.name).collect();
+/// assert_eq!(names, ["queue.1", "queue.9", "queue.10"]);
+/// ```
+pub fn sort_records<T: tabled::Tabled>(
+    data: &mut Vec<T>,
+    keys: &[SortKey],
+) -> Result<(), TableError> {
+    let headers: Vec<String> = T::headers().into_iter().map(|h| h.to_string()).collect();
+    let indices = keys
+        .iter()
+        .map(|key| {
+            headers
+                .iter()
+                .position(|h| header_eq(h, &key.column))
+                .map(|idx| (idx, key.direction))
+                .ok_or_else(|| TableError::UnknownSortKey {
+                    key: key.column.clone(),
+                    available: headers.clone(),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut decorated: Vec<(Vec<String>, T)> = data
+        .drain(..)
+        .map(|item| {
+            let fields = item.fields();
+            let values = indices
+                .iter()
+                .map(|&(idx, _)| fields[idx].to_string())
+                .collect();
+            (values, item)
+        })
+        .collect();
+
+    // Deciding per column keeps the order total: mixing numeric and natural
+    // comparisons can order 3.12 < 3.9 < 3.9.1 < 3.12
+    let numeric: Vec<bool> = (0..indices.len())
+        .map(|key| {
+            let values: Vec<&str> = decorated
+                .iter()
+                .map(|(values, _)| values[key].trim())
+                .filter(|value| !value.is_empty())
+                .collect();
+            values.iter().all(|value| parse_number(value).is_some())
+                && !values.iter().all(|value| looks_like_version(value))
+        })
+        .collect();
+
+    decorated.sort_by(|(a, _), (b, _)| {
+        indices
+            .iter()
+            .zip(&numeric)
+            .zip(a.iter().zip(b))
+            .map(|((&(_, direction), &numeric), (a, b))| {
+                let cmp = if numeric { numeric_cmp } else { natural_cmp };
+                match direction {
+                    SortDirection::Ascending => cmp(a, b),
+                    SortDirection::Descending => cmp(b, a),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    data.extend(decorated.into_iter().map(|(_, item)| item));
+    Ok(())
+}
+
+/// Compares two strings in natural order: runs of digits are compared by their numeric value,
+/// everything else case-insensitively, so `queue.9` sorts before `queue.10`.
+///
+/// # Example
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use bel7_cli::natural_cmp;
+///
+/// assert_eq!(natural_cmp("queue.9", "queue.10"), Ordering::Less);
+/// assert_eq!(natural_cmp("Node2", "node10"), Ordering::Less);
+/// ```
+#[must_use]
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_rest = a;
+    let mut b_rest = b;
+
+    loop {
+        let (a_chunk, a_tail) = split_chunk(a_rest);
+        let (b_chunk, b_tail) = split_chunk(b_rest);
+        let ordering = match (a_chunk, b_chunk) {
+            ("", "") => break,
+            ("", _) => Ordering::Less,
+            (_, "") => Ordering::Greater,
+            (a_chunk, b_chunk) if is_digits(a_chunk) && is_digits(b_chunk) => {
+                digits_cmp(a_chunk, b_chunk)
+            }
+            (a_chunk, b_chunk) => a_chunk
+                .chars()
+                .flat_map(char::to_lowercase)
+                .cmp(b_chunk.chars().flat_map(char::to_lowercase)),
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+        a_rest = a_tail;
+        b_rest = b_tail;
+    }
+
+    // Equal ignoring case and leading zeros: fall back to a plain comparison for a total order
+    a.cmp(b)
+}
+
+/// Compares the values of a numeric column: numbers numerically, after the values that are not numbers.
+fn numeric_cmp(a: &str, b: &str) -> Ordering {
+    match (parse_number(a), parse_number(b)) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (None, None) => natural_cmp(a, b),
+    }
+}
+
+/// Parses a cell value as a number, allowing thousands separators.
+pub(crate) fn parse_number(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if !value.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.')) {
+        return None;
+    }
+    value.replace([',', '_'], "").parse().ok()
+}
+
+/// Returns true if a value looks like a version rather than a number: only digits
+/// separated by dots, such as `3.12` or `3.13.1`. Decimals like `2.5` look the same,
+/// so callers treat values as versions only when every value compared looks like one.
+pub(crate) fn looks_like_version(value: &str) -> bool {
+    let value = value.trim();
+    value.contains('.')
+        && value
+            .split('.')
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Splits off the leading run of either digits or non-digits.
+fn split_chunk(s: &str) -> (&str, &str) {
+    let Some(first) = s.chars().next() else {
+        return ("", "");
+    };
+    let digits = first.is_ascii_digit();
+    let end = s
+        .find(|c: char| c.is_ascii_digit() != digits)
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+fn is_digits(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_digit())
+}
+
+fn digits_cmp(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
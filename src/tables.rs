@@ -14,20 +14,34 @@
 
 //! Table styling utilities for CLI output.
 
-use std::fmt::Display;
+use std::error::Error;
+use std::fmt::{self, Display};
 
 use tabled::Table;
 use tabled::builder::Builder;
+use tabled::settings::Alignment;
 use tabled::settings::Format;
 use tabled::settings::Modify;
 use tabled::settings::Panel;
 use tabled::settings::Remove;
+use tabled::settings::Span;
 use tabled::settings::Width;
 use tabled::settings::object::{Columns, Rows, Segment};
 use tabled::settings::style::Style;
+use tabled::settings::themes::Theme as TabledTheme;
+use tabled::settings::width::Wrap;
 use terminal_size::Width as TermWidth;
 use terminal_size::terminal_size;
 
+use crate::output::should_colorize;
+use crate::sorting::parse_number;
+use crate::theme::{TextStyle, theme};
+use crate::truncate::{
+    abbreviate_ids, ansi_display_width, more_lines_marker, split_lines, strip_ansi, truncate_ansi,
+    truncate_middle_to_width, truncate_to_width,
+};
+use crate::wrap::wrap;
+
 pub use tabled::settings::Padding;
 
 /// Default terminal width when detection fails.
@@ -53,6 +67,93 @@ pub fn responsive_width(utilization: f64) -> usize {
     (width as f64 * utilization.clamp(0.0, 1.0)) as usize
 }
 
+/// Errors caused by invalid user-provided table options, such as `--sort-by` or `--filter` values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError {
+    /// A sort key does not match any column header.
+    UnknownSortKey {
+        /// The key as provided.
+        key: String,
+        /// The column headers that could have been used instead.
+        available: Vec<String>,
+    },
+    /// A sort direction other than `asc` or `desc`.
+    InvalidSortDirection {
+        /// The key the direction was given for.
+        key: String,
+        /// The direction as provided.
+        direction: String,
+    },
+    /// A filter column does not match any column header.
+    UnknownFilterColumn {
+        /// The column as provided.
+        column: String,
+        /// The column headers that could have been used instead.
+        available: Vec<String>,
+    },
+    /// One or more selected columns do not match any column header or alias.
+    UnknownColumns {
+        /// The unknown names as provided, each with the closest valid name, if one is close enough.
+        unknown: Vec<(String, Option<String>)>,
+        /// The column headers that could have been used instead.
+        available: Vec<String>,
+    },
+    /// A malformed filter expression.
+    InvalidFilter {
+        /// The expression as provided.
+        expression: String,
+        /// What is wrong with it.
+        reason: String,
+    },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::UnknownSortKey { key, available } => write!(
+                f,
+                "unknown sort key '{}', expected one of: {}",
+                key,
+                available.join(", ")
+            ),
+            TableError::InvalidSortDirection { key, direction } => write!(
+                f,
+                "invalid sort direction '{}' for '{}', expected 'asc' or 'desc'",
+                direction, key
+            ),
+            TableError::UnknownFilterColumn { column, available } => write!(
+                f,
+                "unknown filter column '{}', expected one of: {}",
+                column,
+                available.join(", ")
+            ),
+            TableError::UnknownColumns { unknown, available } => {
+                let label = if unknown.len() == 1 {
+                    "column"
+                } else {
+                    "columns"
+                };
+                write!(f, "unknown {} ", label)?;
+                for (i, (name, suggestion)) in unknown.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "'{}'", name)?;
+                    if let Some(suggestion) = suggestion {
+                        write!(f, " (did you mean '{}'?)", suggestion)?;
+                    }
+                }
+                write!(f, ", expected one of: {}", available.join(", "))
+            }
+            TableError::InvalidFilter { expression, reason } => {
+                write!(f, "invalid filter '{}': {}", expression, reason)
+            }
+        }
+    }
+}
+
+impl Error for TableError {}
+
 /// Available table styles for CLI output.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -128,8 +229,237 @@ impl TableStyle {
             }
         }
     }
+
+    /// Returns this style without horizontal lines between rows,
+    /// for tables that have no header row.
+    fn without_horizontal_lines(self) -> TabledTheme {
+        let mut theme = self.theme();
+        theme.remove_horizontal_lines();
+        theme
+    }
+
+    fn theme(self) -> TabledTheme {
+        match self {
+            TableStyle::Modern => TabledTheme::from_style(Style::rounded()),
+            TableStyle::Borderless => TabledTheme::from_style(Style::blank()),
+            TableStyle::Markdown => TabledTheme::from_style(Style::markdown()),
+            TableStyle::Sharp => TabledTheme::from_style(Style::sharp()),
+            TableStyle::Ascii => TabledTheme::from_style(Style::ascii()),
+            TableStyle::Psql => TabledTheme::from_style(Style::psql()),
+            TableStyle::Dots => TabledTheme::from_style(Style::dots()),
+        }
+    }
+}
+
+/// Horizontal alignment of a table column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnAlignment {
+    /// Left-aligned (default).
+    #[default]
+    Left,
+    /// Centered.
+    Center,
+    /// Right-aligned, for numbers.
+    Right,
+}
+
+impl From<ColumnAlignment> for Alignment {
+    fn from(alignment: ColumnAlignment) -> Self {
+        match alignment {
+            ColumnAlignment::Left => Alignment::left(),
+            ColumnAlignment::Center => Alignment::center(),
+            ColumnAlignment::Right => Alignment::right(),
+        }
+    }
 }
 
+/// How values that do not fit into a shrunk column are shortened,
+/// see [`StyledTable::column_overflow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnOverflow {
+    /// Cuts values at the end, with a `...` suffix (default).
+    #[default]
+    Truncate,
+    /// Cuts values in the middle, keeping their start and end, for paths and identifiers.
+    /// Drops the ANSI styling of the values it shortens.
+    TruncateMiddle,
+    /// Wraps values at word boundaries onto multiple lines.
+    Wrap,
+}
+
+/// A value computed over the values of a column for a [`FooterRow`].
+///
+/// Values that are not numbers (thousands separators allowed) are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// The sum of the values.
+    Sum,
+    /// The smallest value.
+    Min,
+    /// The largest value.
+    Max,
+    /// The mean of the values, rounded to two decimal places.
+    Avg,
+    /// The number of non-empty values, numbers or not.
+    Count,
+}
+
+impl Aggregate {
+    /// Computes the aggregate over the cell values of a column.
+    ///
+    /// Returns an empty string for the minimum, maximum and mean of a column without numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_cli::Aggregate;
+    ///
+    /// let values = ["1,000", "24", "n/a", ""];
+    /// assert_eq!(Aggregate::Sum.compute(values), "1024");
+    /// assert_eq!(Aggregate::Avg.compute(values), "512");
+    /// assert_eq!(Aggregate::Count.compute(values), "3");
+    /// ```
+    pub fn compute<'a>(self, values: impl IntoIterator<Item = &'a str>) -> String {
+        let values: Vec<String> = values.into_iter().map(strip_ansi).collect();
+        if self == Aggregate::Count {
+            return values
+                .iter()
+                .filter(|v| !v.trim().is_empty())
+                .count()
+                .to_string();
+        }
+
+        let numbers: Vec<f64> = values.iter().filter_map(|v| parse_number(v)).collect();
+        let result = match self {
+            Aggregate::Sum => Some(numbers.iter().sum()),
+            Aggregate::Min => numbers.iter().copied().reduce(f64::min),
+            Aggregate::Max => numbers.iter().copied().reduce(f64::max),
+            Aggregate::Avg => {
+                (!numbers.is_empty()).then(|| numbers.iter().sum::<f64>() / numbers.len() as f64)
+            }
+            Aggregate::Count => None,
+        };
+        result.map(format_number).unwrap_or_default()
+    }
+}
+
+/// Formats an aggregate: integers without a fractional part, other numbers
+/// with up to two decimal places.
+fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        return format!("{}", n as i64);
+    }
+    let formatted = format!("{:.2}", n);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+#[derive(Debug, Clone)]
+enum FooterCell {
+    Text(String),
+    Aggregate(Aggregate),
+}
+
+/// A footer row of a [`StyledTable`], e.g. totals below a listing, see [`StyledTable::footer`].
+///
+/// Cells refer to columns the same way [`ColumnRef`] does. Cells that are not set are empty.
+#[derive(Debug, Clone, Default)]
+pub struct FooterRow {
+    cells: Vec<(ColumnRef, FooterCell)>,
+}
+
+impl FooterRow {
+    /// Creates an empty footer row.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a cell to static text, e.g. `Total`.
+    #[must_use]
+    pub fn text(mut self, column: impl Into<ColumnRef>, text: impl Into<String>) -> Self {
+        self.cells
+            .push((column.into(), FooterCell::Text(text.into())));
+        self
+    }
+
+    /// Sets a cell to an aggregate of the column values.
+    #[must_use]
+    pub fn aggregate(mut self, column: impl Into<ColumnRef>, aggregate: Aggregate) -> Self {
+        self.cells
+            .push((column.into(), FooterCell::Aggregate(aggregate)));
+        self
+    }
+}
+
+/// Refers to a table column by its 0-based index or by its header (matched case-insensitively).
+///
+/// Indices and headers refer to the columns as rendered, that is, after column selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnRef {
+    /// A 0-based column index.
+    Index(usize),
+    /// A column header.
+    Name(String),
+}
+
+impl ColumnRef {
+    /// Returns the index of this column in `headers`, if present.
+    pub fn resolve(&self, headers: &[String]) -> Option<usize> {
+        match self {
+            ColumnRef::Index(idx) => (*idx < headers.len()).then_some(*idx),
+            ColumnRef::Name(name) => headers.iter().position(|h| header_eq(h, name)),
+        }
+    }
+}
+
+impl From<usize> for ColumnRef {
+    fn from(idx: usize) -> Self {
+        ColumnRef::Index(idx)
+    }
+}
+
+impl From<&str> for ColumnRef {
+    fn from(name: &str) -> Self {
+        ColumnRef::Name(name.to_string())
+    }
+}
+
+impl From<String> for ColumnRef {
+    fn from(name: String) -> Self {
+        ColumnRef::Name(name)
+    }
+}
+
+/// When to render a table in the expanded (vertical) layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExpandedMode {
+    Never,
+    Always,
+    WiderThan(f64),
+}
+
+/// A column to wrap, see [`StyledTable::wrap_column`].
+#[derive(Debug, Clone)]
+struct WrappedColumn {
+    column: ColumnRef,
+    width: usize,
+    keep_words: bool,
+}
+
+impl WrappedColumn {
+    fn setting(&self) -> Wrap {
+        Width::wrap(self.width).keep_words(self.keep_words)
+    }
+}
+
+/// Picks a style for a cell value, see [`StyledTable::style_cells`].
+type CellStyler = Box<dyn Fn(&str) -> Option<TextStyle> + Send + Sync>;
+
 /// A builder for styled tables.
 pub struct StyledTable {
     style: TableStyle,
@@ -138,7 +468,23 @@ pub struct StyledTable {
     padding: Option<Padding>,
     newline_replacement: Option<String>,
     max_width: Option<usize>,
-    wrap_column: Option<(usize, usize)>,
+    wrapped_columns: Vec<WrappedColumn>,
+    columns: Option<Vec<String>>,
+    max_value_chars: Option<usize>,
+    max_value_lines: Option<usize>,
+    abbreviated_columns: Vec<(ColumnRef, usize)>,
+    alignments: Vec<(ColumnRef, ColumnAlignment)>,
+    align_numbers_right: bool,
+    cell_stylers: Vec<(ColumnRef, CellStyler)>,
+    colorize: Option<bool>,
+    display_names: Vec<(ColumnRef, String)>,
+    column_priorities: Vec<(ColumnRef, u32)>,
+    min_widths: Vec<(ColumnRef, usize)>,
+    max_widths: Vec<(ColumnRef, usize)>,
+    overflows: Vec<(ColumnRef, ColumnOverflow)>,
+    fixed_columns: Vec<ColumnRef>,
+    footers: Vec<FooterRow>,
+    expanded: ExpandedMode,
 }
 
 impl Default for StyledTable {
@@ -158,23 +504,87 @@ impl StyledTable {
             padding: None,
             newline_replacement: None,
             max_width: None,
-            wrap_column: None,
+            wrapped_columns: Vec::new(),
+            columns: None,
+            max_value_chars: None,
+            max_value_lines: None,
+            abbreviated_columns: Vec::new(),
+            alignments: Vec::new(),
+            align_numbers_right: false,
+            cell_stylers: Vec::new(),
+            colorize: None,
+            display_names: Vec::new(),
+            column_priorities: Vec::new(),
+            min_widths: Vec::new(),
+            max_widths: Vec::new(),
+            overflows: Vec::new(),
+            fixed_columns: Vec::new(),
+            footers: Vec::new(),
+            expanded: ExpandedMode::Never,
         }
     }
 
     /// Sets maximum width for the table (enables responsive layout).
+    ///
+    /// Wider tables are shrunk column by column: the width available for cell content is
+    /// distributed proportionally to the content width of every column, within the
+    /// [minimum](Self::column_min_width) and [maximum](Self::column_max_width) column widths,
+    /// and [fixed](Self::fixed_column) columns are shrunk last. With
+    /// [column priorities](Self::column_priority), low priority columns are hidden first.
+    /// When even the minimum widths do not fit, columns are shrunk below them.
     #[must_use]
     pub fn max_width(mut self, width: usize) -> Self {
         self.max_width = Some(width);
         self
     }
 
-    /// Sets a column to wrap at a specific width.
+    /// Wraps a column at a specific width, splitting words if necessary.
+    ///
+    /// Can be called for any number of columns. In the expanded and details layouts,
+    /// a column given by name wraps the values of that field, while column indices
+    /// refer to the two columns of the layout.
+    #[must_use]
+    pub fn wrap_column(self, column: impl Into<ColumnRef>, width: usize) -> Self {
+        self.push_wrapped_column(column.into(), width, false)
+    }
+
+    /// Wraps a column at a specific width at word boundaries, keeping words intact.
+    /// Only words wider than the column are split.
+    ///
+    /// # Example
     ///
-    /// Column index is 0-based.
+    /// ```
+    /// use bel7_cli::{StyledTable, TableStyle};
+    /// use tabled::Tabled;
+    ///
+    /// #[derive(Tabled)]
+    /// struct Policy {
+    ///     pattern: &'static str,
+    ///     definition: &'static str,
+    /// }
+    ///
+    /// let policies = vec![Policy {
+    ///     pattern: "^orders\\.",
+    ///     definition: "max-length: 1000 overflow: reject-publish",
+    /// }];
+    /// let table = StyledTable::new()
+    ///     .style(TableStyle::Psql)
+    ///     .wrap_column("pattern", 4)
+    ///     .wrap_column_keep_words("definition", 20)
+    ///     .build(policies);
+    /// assert!(table.to_string().contains(" ^ord | max-length: 1000 "));
+    /// ```
     #[must_use]
-    pub fn wrap_column(mut self, column_index: usize, width: usize) -> Self {
-        self.wrap_column = Some((column_index, width));
+    pub fn wrap_column_keep_words(self, column: impl Into<ColumnRef>, width: usize) -> Self {
+        self.push_wrapped_column(column.into(), width, true)
+    }
+
+    fn push_wrapped_column(mut self, column: ColumnRef, width: usize, keep_words: bool) -> Self {
+        self.wrapped_columns.push(WrappedColumn {
+            column,
+            width,
+            keep_words,
+        });
         self
     }
 
@@ -215,25 +625,899 @@ impl StyledTable {
         self
     }
 
+    /// Limits the table to the given columns, in the given order.
+    ///
+    /// Columns are matched case-insensitively against the `Tabled` headers,
+    /// usually after [`parse_columns`] or [`resolve_columns`]. Unknown columns are ignored.
+    /// Headers are rendered as declared by `T::headers()`, or with their
+    /// [display names](Self::rename_column).
+    pub fn columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Renders a column header under a different name, e.g. `Messages Ready` for `messages_ready`.
+    ///
+    /// Other options, such as [`columns`](Self::columns), still refer to the column by its `Tabled` header.
+    pub fn rename_column(
+        mut self,
+        column: impl Into<ColumnRef>,
+        display_name: impl Into<String>,
+    ) -> Self {
+        self.display_names
+            .push((column.into(), display_name.into()));
+        self
+    }
+
+    /// Truncates cell values wider than `max_chars` columns using [`truncate_ansi`],
+    /// so values that are already colored keep their styling.
+    ///
+    /// Multi-line values are truncated line by line.
+    pub fn truncate_values(mut self, max_chars: usize) -> Self {
+        self.max_value_chars = Some(max_chars);
+        self
+    }
+
+    /// Limits multi-line cell values (stack traces, policy definitions, JSON documents)
+    /// to `max_lines` lines, followed by a dimmed `… (N more lines)` marker.
+    ///
+    /// Composes with [`truncate_values`](Self::truncate_values).
+    pub fn truncate_lines(mut self, max_lines: usize) -> Self {
+        self.max_value_lines = Some(max_lines);
+        self
+    }
+
+    /// Abbreviates the identifiers (hashes, UUIDs, message IDs) in a column
+    /// to the shortest prefix, no shorter than `min_len`, that keeps them unique
+    /// within the table. See [`abbreviate_ids`](crate::abbreviate_ids).
+    pub fn abbreviate_ids(mut self, column: impl Into<ColumnRef>, min_len: usize) -> Self {
+        self.abbreviated_columns.push((column.into(), min_len));
+        self
+    }
+
+    /// Sets the alignment of a column, including its header.
+    pub fn align_column(
+        mut self,
+        column: impl Into<ColumnRef>,
+        alignment: ColumnAlignment,
+    ) -> Self {
+        self.alignments.push((column.into(), alignment));
+        self
+    }
+
+    /// Right-aligns columns where every non-empty value is a number,
+    /// a percentage or a human-readable size (`1,024`, `-3.5`, `97%`, `12 MiB`, `1.5 GB/s`).
+    ///
+    /// Alignments set with [`align_column`](Self::align_column) take precedence.
+    pub fn align_numbers_right(mut self) -> Self {
+        self.align_numbers_right = true;
+        self
+    }
+
+    /// Styles the cells of a column with the style `styler` picks for the cell value,
+    /// if any. When several rules match a cell, the one added first wins.
+    ///
+    /// Rules see the original value, before truncation, and are applied when the table
+    /// is built, only if colors are enabled (see [`colorize`](Self::colorize)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_cli::{StyledTable, TextStyle, ThemeColor};
+    ///
+    /// let table = StyledTable::new().style_cells("messages", |value| {
+    ///     let count: u64 = value.replace(',', "").parse().ok()?;
+    ///     (count > 10_000).then(|| TextStyle::new().color(ThemeColor::Yellow))
+    /// });
+    /// ```
+    pub fn style_cells(
+        mut self,
+        column: impl Into<ColumnRef>,
+        styler: impl Fn(&str) -> Option<TextStyle> + Send + Sync + 'static,
+    ) -> Self {
+        self.cell_stylers.push((column.into(), Box::new(styler)));
+        self
+    }
+
+    /// Styles the cells of a column for which `predicate` returns true.
+    ///
+    /// See [`style_cells`](Self::style_cells).
+    pub fn style_when(
+        self,
+        column: impl Into<ColumnRef>,
+        predicate: impl Fn(&str) -> bool + Send + Sync + 'static,
+        style: TextStyle,
+    ) -> Self {
+        self.style_cells(column, move |value| predicate(value).then_some(style))
+    }
+
+    /// Styles the cells of a column by their exact value, for example
+    /// `running` in green and `crashed` in bold red.
+    ///
+    /// See [`style_cells`](Self::style_cells).
+    pub fn style_values<K: Into<String>>(
+        self,
+        column: impl Into<ColumnRef>,
+        styles: impl IntoIterator<Item = (K, TextStyle)>,
+    ) -> Self {
+        let styles: Vec<(String, TextStyle)> = styles
+            .into_iter()
+            .map(|(k, style)| (k.into(), style))
+            .collect();
+        self.style_cells(column, move |value| {
+            styles
+                .iter()
+                .find(|(k, _)| k == value)
+                .map(|(_, style)| *style)
+        })
+    }
+
+    /// Overrides whether cell styling rules and dimmed markers are rendered.
+    ///
+    /// By default, this follows [`should_colorize`].
+    pub fn colorize(mut self, enabled: bool) -> Self {
+        self.colorize = Some(enabled);
+        self
+    }
+
+    /// Renders every row as its own block of `header | value` lines,
+    /// separated by `-[ RECORD n ]-` lines, like `\x` in `psql`.
+    ///
+    /// The expanded layout does not use the table style.
+    pub fn expanded(mut self) -> Self {
+        self.expanded = ExpandedMode::Always;
+        self
+    }
+
+    /// Switches to the [expanded](Self::expanded) layout when the natural table width
+    /// exceeds `ratio` times the [`terminal_width`].
+    ///
+    /// For example, with a ratio of `1.0` tables that would not fit
+    /// into the terminal are rendered expanded.
+    pub fn expanded_when_wider_than(mut self, ratio: f64) -> Self {
+        self.expanded = ExpandedMode::WiderThan(ratio);
+        self
+    }
+
     /// Builds the final table from the provided data.
+    ///
+    /// Columns hidden to fit the [maximum width](Self::max_width) are not mentioned,
+    /// see [`render`](Self::render) for output that names them.
     pub fn build<T: tabled::Tabled>(self, data: Vec<T>) -> Table {
-        let mut table = Table::new(data);
+        self.build_with_hidden_columns(data).0
+    }
+
+    /// Builds the table and renders it, followed by a dimmed note naming the columns
+    /// that were hidden to fit the [maximum width](Self::max_width), if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_cli::StyledTable;
+    /// use tabled::Tabled;
+    ///
+    /// #[derive(Tabled)]
+    /// struct Queue {
+    ///     name: String,
+    ///     vhost: String,
+    ///     messages: u64,
+    /// }
+    ///
+    /// let queues = vec![Queue { name: "orders".into(), vhost: "production".into(), messages: 12 }];
+    /// let output = StyledTable::new()
+    ///     .colorize(false)
+    ///     .max_width(24)
+    ///     .column_priority("name", 2)
+    ///     .column_priority("messages", 1)
+    ///     .render(queues);
+    /// assert!(output.ends_with("Hidden columns: vhost"));
+    /// ```
+    pub fn render<T: tabled::Tabled>(self, data: Vec<T>) -> String {
+        let colorize = self.colorize.unwrap_or_else(should_colorize);
+        let (table, hidden) = self.build_with_hidden_columns(data);
+        if hidden.is_empty() {
+            return table.to_string();
+        }
+
+        let note = format!("Hidden columns: {}", hidden.join(", "));
+        if colorize {
+            format!("{}\n{}", table, theme().dimmed.style.paint(note))
+        } else {
+            format!("{}\n{}", table, note)
+        }
+    }
+
+    /// Sets the priority of a column for [`max_width`](Self::max_width) layouts.
+    ///
+    /// When at least one priority is set and the table is wider than the maximum width,
+    /// columns are hidden, lowest priority first, before the remaining ones are truncated.
+    /// Columns default to priority 0. Among columns of the same priority, the rightmost one
+    /// is hidden first. The last remaining column is never hidden.
+    pub fn column_priority(mut self, column: impl Into<ColumnRef>, priority: u32) -> Self {
+        self.column_priorities.push((column.into(), priority));
+        self
+    }
+
+    /// Sets the width a column is not shrunk below to fit the [maximum width](Self::max_width).
+    ///
+    /// Defaults to the width of the column header. Columns only go below their minimum
+    /// width when the minimum widths of all visible columns do not fit.
+    pub fn column_min_width(mut self, column: impl Into<ColumnRef>, width: usize) -> Self {
+        self.min_widths.push((column.into(), width));
+        self
+    }
+
+    /// Limits a column to `width` terminal columns, with or without a [maximum table width](Self::max_width).
+    pub fn column_max_width(mut self, column: impl Into<ColumnRef>, width: usize) -> Self {
+        self.max_widths.push((column.into(), width));
+        self
+    }
+
+    /// Sets how the values of a column are shortened when it is shrunk or limited to
+    /// a [maximum width](Self::column_max_width). Defaults to [`ColumnOverflow::Truncate`].
+    pub fn column_overflow(
+        mut self,
+        column: impl Into<ColumnRef>,
+        overflow: ColumnOverflow,
+    ) -> Self {
+        self.overflows.push((column.into(), overflow));
+        self
+    }
+
+    /// Marks a column, such as a name or a state, as fixed: it is shrunk to fit the
+    /// [maximum width](Self::max_width) only after all other columns are down to their
+    /// [minimum width](Self::column_min_width).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_cli::{ColumnOverflow, StyledTable, TableStyle};
+    /// use tabled::Tabled;
+    ///
+    /// #[derive(Tabled)]
+    /// struct Binding {
+    ///     source: &'static str,
+    ///     arguments: &'static str,
+    /// }
+    ///
+    /// let bindings = vec![Binding {
+    ///     source: "amq.topic",
+    ///     arguments: "x-match: all, region: eu-west",
+    /// }];
+    /// let table = StyledTable::new()
+    ///     .style(TableStyle::Psql)
+    ///     .max_width(30)
+    ///     .fixed_column("source")
+    ///     .column_overflow("arguments", ColumnOverflow::Wrap)
+    ///     .build(bindings);
+    /// assert!(table.to_string().contains(" amq.topic | x-match: all, "));
+    /// ```
+    pub fn fixed_column(mut self, column: impl Into<ColumnRef>) -> Self {
+        self.fixed_columns.push(column.into());
+        self
+    }
+
+    /// Adds a footer row below the data rows, separated from them like the header row is.
+    ///
+    /// Footers are omitted along with the header row (see [`remove_header_row`](Self::remove_header_row)),
+    /// as well as in the expanded and details layouts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_cli::{Aggregate, FooterRow, StyledTable, TableStyle};
+    /// use tabled::Tabled;
+    ///
+    /// #[derive(Tabled)]
+    /// struct Queue {
+    ///     name: &'static str,
+    ///     messages: u64,
+    /// }
+    ///
+    /// let queues = vec![
+    ///     Queue { name: "orders", messages: 12 },
+    ///     Queue { name: "events", messages: 30 },
+    /// ];
+    /// let table = StyledTable::new()
+    ///     .style(TableStyle::Psql)
+    ///     .footer(FooterRow::new().text("name", "Total").aggregate("messages", Aggregate::Sum))
+    ///     .build(queues);
+    /// assert!(table.to_string().ends_with("--------+----------\n Total  | 42       "));
+    /// ```
+    pub fn footer(mut self, row: FooterRow) -> Self {
+        self.footers.push(row);
+        self
+    }
+
+    /// Builds the table, returning it with the display names of the hidden columns.
+    fn build_with_hidden_columns<T: tabled::Tabled>(
+        mut self,
+        data: Vec<T>,
+    ) -> (Table, Vec<String>) {
+        let (headers, values) = self.values(&data);
+        let mut rows = self.cells(&headers, &values);
+
+        if self.expanded == ExpandedMode::Always {
+            return (self.build_expanded(headers, rows), Vec::new());
+        }
+
+        let display = self.display_headers(&headers);
+        let alignments = self.column_alignments(&headers, &rows);
+        let all: Vec<usize> = (0..headers.len()).collect();
+
+        if let ExpandedMode::WiderThan(ratio) = self.expanded {
+            let limit = terminal_width() as f64 * ratio.max(0.0);
+            if self.table(&display, &rows, &alignments, &all).total_width() as f64 > limit {
+                return (self.build_expanded(headers, rows), Vec::new());
+            }
+        }
+
+        // Footers take part in the layout like any other row
+        let footers = self.footer_rows(&headers, &values);
+        let footer_count = footers.len();
+        rows.extend(footers);
+
+        let visible = self.visible_columns(&headers, &display, &rows, &alignments);
+        let widths = self.column_widths(&headers, &display, &rows, &visible);
+        self.wrapped_columns = self.visible_wrapped_columns(&headers, &visible);
 
+        let hidden = all
+            .iter()
+            .filter(|idx| !visible.contains(idx))
+            .map(|&idx| display[idx].clone())
+            .collect();
+        let (display, rows) = self.fit_columns(&headers, display, rows, &widths);
+        let table = self.table(&display, &rows, &alignments, &visible);
+        (self.finish(table, true, footer_count, &[]), hidden)
+    }
+
+    /// Builds a styled, aligned table with only the `visible` columns.
+    fn table(
+        &self,
+        headers: &[String],
+        rows: &[Vec<String>],
+        alignments: &[Option<ColumnAlignment>],
+        visible: &[usize],
+    ) -> Table {
+        let mut builder = Builder::default();
+        builder.push_record(visible.iter().map(|&idx| headers[idx].clone()));
+        for row in rows {
+            builder.push_record(visible.iter().map(|&idx| row[idx].clone()));
+        }
+        let mut table = builder.build();
         self.style.apply(&mut table);
 
+        for (position, &idx) in visible.iter().enumerate() {
+            if let Some(alignment) = alignments[idx] {
+                table.with(Modify::new(Columns::one(position)).with(Alignment::from(alignment)));
+            }
+        }
+        table
+    }
+
+    /// Returns the columns to keep so that the table fits into the maximum width,
+    /// hiding the lowest priority columns first.
+    fn visible_columns(
+        &self,
+        headers: &[String],
+        display: &[String],
+        rows: &[Vec<String>],
+        alignments: &[Option<ColumnAlignment>],
+    ) -> Vec<usize> {
+        let mut visible: Vec<usize> = (0..headers.len()).collect();
+        let Some(max_width) = self.max_width else {
+            return visible;
+        };
+        if self.column_priorities.is_empty() {
+            return visible;
+        }
+
+        let mut priorities = vec![0; headers.len()];
+        for (column, priority) in &self.column_priorities {
+            if let Some(idx) = column.resolve(headers) {
+                priorities[idx] = *priority;
+            }
+        }
+
+        while visible.len() > 1
+            && self.natural_width(headers, display, rows, alignments, &visible) > max_width
+        {
+            // `min_by_key` returns the first minimum, so iterate right to left
+            let position = (0..visible.len())
+                .rev()
+                .min_by_key(|&position| priorities[visible[position]])
+                .unwrap_or_default();
+            visible.remove(position);
+        }
+        visible
+    }
+
+    /// Returns the width of the table with only the `visible` columns, before truncation.
+    fn natural_width(
+        &self,
+        headers: &[String],
+        display: &[String],
+        rows: &[Vec<String>],
+        alignments: &[Option<ColumnAlignment>],
+        visible: &[usize],
+    ) -> usize {
+        let mut table = self.table(display, rows, alignments, visible);
         if let Some(padding) = self.padding {
             table.with(padding);
         }
+        if let Some(replacement) = &self.newline_replacement {
+            let replacement = replacement.clone();
+            table.with(
+                Modify::new(Segment::all())
+                    .with(Format::content(move |s| s.replace('\n', &replacement))),
+            );
+        }
+        for wrapped in self.visible_wrapped_columns(headers, visible) {
+            if let ColumnRef::Index(position) = wrapped.column {
+                table.with(Modify::new(Columns::one(position)).with(wrapped.setting()));
+            }
+        }
+        table.total_width()
+    }
 
-        // Remove column headers before adding panel header
+    /// Returns the width every `visible` column has to be shortened to, if any,
+    /// to respect the column width limits and fit into the maximum width.
+    fn column_widths(
+        &self,
+        headers: &[String],
+        display: &[String],
+        rows: &[Vec<String>],
+        visible: &[usize],
+    ) -> Vec<Option<usize>> {
+        let mut natural = vec![0; headers.len()];
+        for &idx in visible {
+            natural[idx] = std::iter::once(&display[idx])
+                .chain(rows.iter().map(|row| &row[idx]))
+                .flat_map(|value| value.split('\n'))
+                .map(ansi_display_width)
+                .max()
+                .unwrap_or_default();
+        }
+        for wrapped in &self.wrapped_columns {
+            if let Some(idx) = wrapped.column.resolve(headers) {
+                natural[idx] = natural[idx].min(wrapped.width);
+            }
+        }
+
+        let mut widths = natural.clone();
+        for (column, max) in &self.max_widths {
+            if let Some(idx) = column.resolve(headers) {
+                widths[idx] = widths[idx].min(*max);
+            }
+        }
+
+        if let Some(max_width) = self.max_width {
+            let mut min_widths: Vec<usize> =
+                display.iter().map(|h| ansi_display_width(h)).collect();
+            for (column, min) in &self.min_widths {
+                if let Some(idx) = column.resolve(headers) {
+                    min_widths[idx] = *min;
+                }
+            }
+            for (min, width) in min_widths.iter_mut().zip(&widths) {
+                *min = (*min).min(*width);
+            }
+
+            // Borders and padding: the width of the same table with empty cells
+            let empty = vec![String::new(); headers.len()];
+            let mut frame = self.table(&empty, &[], &vec![None; headers.len()], visible);
+            if let Some(padding) = self.padding {
+                frame.with(padding);
+            }
+            let available = max_width.saturating_sub(frame.total_width());
+
+            let (fixed, flexible): (Vec<usize>, Vec<usize>) = visible.iter().partition(|&&idx| {
+                self.fixed_columns
+                    .iter()
+                    .any(|column| column.resolve(headers) == Some(idx))
+            });
+            let total = |columns: &[usize], widths: &[usize]| -> usize {
+                columns.iter().map(|&idx| widths[idx]).sum()
+            };
+            let budget = available.saturating_sub(total(&fixed, &widths));
+            shrink_proportionally(&mut widths, &min_widths, &flexible, budget);
+            let budget = available.saturating_sub(total(&flexible, &widths));
+            shrink_proportionally(&mut widths, &min_widths, &fixed, budget);
+
+            // When the minimum widths do not fit either, go below them rather than leave it
+            // to the final table truncation, which cuts wrapped values without a marker
+            let one_column: Vec<usize> = widths.iter().map(|&width| width.min(1)).collect();
+            shrink_proportionally(&mut widths, &one_column, visible, available);
+        }
+
+        widths
+            .into_iter()
+            .zip(natural)
+            .map(|(width, natural)| (width < natural).then_some(width))
+            .collect()
+    }
+
+    /// Shortens the headers and values of the columns that have a width in `widths`
+    /// according to their overflow strategy.
+    fn fit_columns(
+        &self,
+        headers: &[String],
+        mut display: Vec<String>,
+        mut rows: Vec<Vec<String>>,
+        widths: &[Option<usize>],
+    ) -> (Vec<String>, Vec<Vec<String>>) {
+        let mut overflows = vec![ColumnOverflow::default(); headers.len()];
+        for (column, overflow) in &self.overflows {
+            if let Some(idx) = column.resolve(headers) {
+                overflows[idx] = *overflow;
+            }
+        }
+
+        for (idx, width) in widths.iter().enumerate() {
+            let Some(width) = *width else {
+                continue;
+            };
+            let fit = |value: &str| match overflows[idx] {
+                ColumnOverflow::Truncate => value
+                    .split('\n')
+                    .map(|line| truncate_ansi(line, width))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                ColumnOverflow::TruncateMiddle => value
+                    .split('\n')
+                    .map(|line| {
+                        if ansi_display_width(line) <= width {
+                            line.to_string()
+                        } else {
+                            truncate_middle_to_width(&strip_ansi(line), width)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                ColumnOverflow::Wrap => wrap(value, width).join("\n"),
+            };
+            display[idx] = fit(&display[idx]);
+            for row in &mut rows {
+                row[idx] = fit(&row[idx]);
+            }
+        }
+        (display, rows)
+    }
+
+    /// Returns the wrapped columns that are visible, referring to them by their index
+    /// among the `visible` columns.
+    fn visible_wrapped_columns(&self, headers: &[String], visible: &[usize]) -> Vec<WrappedColumn> {
+        self.wrapped_columns
+            .iter()
+            .filter_map(|wrapped| {
+                let col_idx = wrapped.column.resolve(headers)?;
+                let position = visible.iter().position(|&idx| idx == col_idx)?;
+                Some(WrappedColumn {
+                    column: ColumnRef::Index(position),
+                    ..wrapped.clone()
+                })
+            })
+            .collect()
+    }
+
+    fn build_expanded(mut self, headers: Vec<String>, rows: Vec<Vec<String>>) -> Table {
+        let wrapped_fields = self.take_wrapped_fields(&headers);
+        let headers = self.display_headers(&headers);
+        let mut builder = Builder::default();
+        let mut spanned_rows = Vec::with_capacity(rows.len() + 1);
+        // The header panel is added here rather than in `finish`
+        // so that it does not shift the separator rows
+        if let Some(header) = self.header.take() {
+            spanned_rows.push(builder.count_records());
+            builder.push_record([header, String::new()]);
+        }
+        let mut separator_rows = Vec::with_capacity(rows.len());
+        let mut wrapped_cells = Vec::new();
+        for (n, row) in rows.into_iter().enumerate() {
+            separator_rows.push((builder.count_records(), n + 1));
+            builder.push_record([String::new(), String::new()]);
+            for ((name, value), wrapped) in headers.iter().zip(row).zip(&wrapped_fields) {
+                if let Some(wrapped) = wrapped {
+                    wrapped_cells.push((builder.count_records(), wrapped));
+                }
+                builder.push_record([name.clone(), value]);
+            }
+        }
+        spanned_rows.extend(separator_rows.iter().map(|&(row, _)| row));
+        let mut table = builder.build();
+
+        table.with(Style::empty().vertical('|'));
+        table.modify(Columns::first(), Padding::new(0, 1, 0, 0));
+        table.modify(Columns::last(), Padding::new(1, 0, 0, 0));
+        for &row in &spanned_rows {
+            table.modify((row, 0), Span::column(2));
+            table.modify((row, 0), Padding::zero());
+        }
+
+        let mut table = self.finish(table, false, 0, &wrapped_cells);
+
+        // Separators are filled in last, so that they span the table width
+        // without affecting the column widths
+        let width = table.total_width();
+        for (row, n) in separator_rows {
+            let separator = format!("{:-<width$}", format!("-[ RECORD {} ]-", n));
+            table.modify((row, 0), Format::content(move |_| separator.clone()));
+        }
+        table
+    }
+
+    /// Builds a two-column property list (field name, then value) for a single record.
+    ///
+    /// Intended for `show`/`get` style commands that print one entity with many fields.
+    /// Respects the style, padding, header panel, column selection, value truncation,
+    /// newline replacement and width settings. There is no header row and no separator lines between rows,
+    /// except below the header panel, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bel7_cli::{StyledTable, TableStyle};
+    /// use tabled::Tabled;
+    ///
+    /// #[derive(Tabled)]
+    /// struct Queue {
+    ///     name: String,
+    ///     messages: u64,
+    /// }
+    ///
+    /// let queue = Queue { name: "orders".into(), messages: 12 };
+    /// let table = StyledTable::new()
+    ///     .style(TableStyle::Psql)
+    ///     .build_details(&queue);
+    /// assert_eq!(table.to_string(), " name     | orders \n messages | 12     ");
+    /// ```
+    pub fn build_details<T: tabled::Tabled>(mut self, item: &T) -> Table {
+        let (headers, mut rows) = self.records(std::slice::from_ref(item));
+        let values = rows.pop().unwrap_or_default();
+
+        let mut builder = Builder::default();
+        for (name, value) in self.display_headers(&headers).into_iter().zip(values) {
+            builder.push_record([name, value]);
+        }
+        let mut table = builder.build();
+        let wrapped_fields = self.take_wrapped_fields(&headers);
+        let wrapped_cells: Vec<_> = wrapped_fields
+            .iter()
+            .enumerate()
+            .filter_map(|(row, wrapped)| Some((row, wrapped.as_ref()?)))
+            .collect();
+
+        // With a header panel, the style's header separator ends up right below the panel
+        if self.header.is_some() {
+            self.style.apply(&mut table);
+        } else {
+            table.with(self.style.without_horizontal_lines());
+        }
+        self.finish(table, false, 0, &wrapped_cells)
+    }
+
+    /// Takes the columns wrapped by name, by field index, for the layouts that render
+    /// every field as a row. Columns wrapped by index are kept for [`finish`](Self::finish).
+    fn take_wrapped_fields(&mut self, headers: &[String]) -> Vec<Option<WrappedColumn>> {
+        let mut fields = vec![None; headers.len()];
+        self.wrapped_columns.retain(|wrapped| match wrapped.column {
+            ColumnRef::Index(_) => true,
+            ColumnRef::Name(_) => {
+                if let Some(idx) = wrapped.column.resolve(headers) {
+                    fields[idx] = Some(wrapped.clone());
+                }
+                false
+            }
+        });
+        fields
+    }
+
+    /// Extracts the header and the data rows, applying column selection, identifier abbreviation
+    /// and value truncation.
+    fn records<T: tabled::Tabled>(&self, data: &[T]) -> (Vec<String>, Vec<Vec<String>>) {
+        let (headers, values) = self.values(data);
+        let rows = self.cells(&headers, &values);
+        (headers, rows)
+    }
+
+    /// Extracts the header and the values of the data rows, applying column selection
+    /// and identifier abbreviation.
+    fn values<T: tabled::Tabled>(&self, data: &[T]) -> (Vec<String>, Vec<Vec<String>>) {
+        let headers: Vec<String> = T::headers().into_iter().map(|h| h.to_string()).collect();
+        let indices: Vec<usize> = match &self.columns {
+            Some(columns) => column_indices(&headers, columns),
+            None => (0..headers.len()).collect(),
+        };
+
+        let selected_headers: Vec<String> =
+            indices.iter().map(|&idx| headers[idx].clone()).collect();
+        let mut values: Vec<Vec<String>> = data
+            .iter()
+            .map(|item| {
+                let fields = item.fields();
+                indices.iter().map(|&idx| fields[idx].to_string()).collect()
+            })
+            .collect();
+
+        for (column, min_len) in &self.abbreviated_columns {
+            if let Some(idx) = column.resolve(&selected_headers) {
+                let ids: Vec<&str> = values.iter().map(|row| row[idx].as_str()).collect();
+                let abbreviated = abbreviate_ids(&ids, *min_len);
+                for (row, id) in values.iter_mut().zip(abbreviated) {
+                    row[idx] = id;
+                }
+            }
+        }
+
+        (selected_headers, values)
+    }
+
+    /// Applies value truncation and cell styles to the values of the data rows.
+    fn cells(&self, headers: &[String], values: &[Vec<String>]) -> Vec<Vec<String>> {
+        let colorize = self.colorize.unwrap_or_else(should_colorize);
+        let stylers: Vec<(usize, &CellStyler)> = if colorize {
+            self.cell_stylers
+                .iter()
+                .filter_map(|(column, styler)| Some((column.resolve(headers)?, styler)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        values
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(idx, value)| {
+                        let style = stylers
+                            .iter()
+                            .filter(|(column, _)| *column == idx)
+                            .find_map(|(_, styler)| styler(value));
+                        self.cell(value, style, colorize)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the footer rows, with their aggregates computed over the values of the data rows.
+    ///
+    /// Tables without a header row have no footers either.
+    fn footer_rows(&self, headers: &[String], values: &[Vec<String>]) -> Vec<Vec<String>> {
         if self.remove_header_row {
+            return Vec::new();
+        }
+
+        self.footers
+            .iter()
+            .map(|footer| {
+                let mut row = vec![String::new(); headers.len()];
+                for (column, cell) in &footer.cells {
+                    let Some(idx) = column.resolve(headers) else {
+                        continue;
+                    };
+                    row[idx] = match cell {
+                        FooterCell::Text(text) => text.clone(),
+                        FooterCell::Aggregate(aggregate) => {
+                            aggregate.compute(values.iter().map(|row| row[idx].as_str()))
+                        }
+                    };
+                }
+                row
+            })
+            .collect()
+    }
+
+    /// Returns the headers to render, with display names applied.
+    fn display_headers(&self, headers: &[String]) -> Vec<String> {
+        let mut display = headers.to_vec();
+        for (column, name) in &self.display_names {
+            if let Some(idx) = column.resolve(headers) {
+                display[idx] = name.clone();
+            }
+        }
+        display
+    }
+
+    /// Returns the alignment of every column, if one is set or detected.
+    fn column_alignments(
+        &self,
+        headers: &[String],
+        rows: &[Vec<String>],
+    ) -> Vec<Option<ColumnAlignment>> {
+        let mut alignments = vec![None; headers.len()];
+        if self.align_numbers_right {
+            for (idx, alignment) in alignments.iter_mut().enumerate() {
+                let mut values = rows
+                    .iter()
+                    .map(|row| row[idx].as_str())
+                    .filter(|v| !v.trim().is_empty());
+                let first = values.next();
+                if first.is_some_and(is_numeric) && values.all(is_numeric) {
+                    *alignment = Some(ColumnAlignment::Right);
+                }
+            }
+        }
+        for (column, alignment) in &self.alignments {
+            if let Some(idx) = column.resolve(headers) {
+                alignments[idx] = Some(*alignment);
+            }
+        }
+        alignments
+    }
+
+    /// Applies line and width truncation and the cell style to a cell value.
+    fn cell(&self, value: &str, style: Option<TextStyle>, colorize: bool) -> String {
+        let (kept, hidden) = match self.max_value_lines.and_then(|max| split_lines(value, max)) {
+            Some((kept, hidden)) => (kept, Some(hidden)),
+            None => (value, None),
+        };
+
+        let mut lines: Vec<String> = match self.max_value_chars {
+            _ if kept.is_empty() && hidden.is_some() => Vec::new(),
+            Some(max_chars) => kept
+                .split('\n')
+                .map(|line| truncate_ansi(line, max_chars))
+                .collect(),
+            None => vec![kept.to_string()],
+        };
+        if let Some(style) = style {
+            for line in &mut lines {
+                *line = style.paint(&line);
+            }
+        }
+
+        if let Some(hidden) = hidden {
+            let marker = more_lines_marker(hidden);
+            let marker = match self.max_value_chars {
+                Some(max_chars) => truncate_to_width(&marker, max_chars),
+                None => marker,
+            };
+            if colorize {
+                lines.push(theme().dimmed.style.paint(marker));
+            } else {
+                lines.push(marker);
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Applies the settings shared by all layouts.
+    ///
+    /// `wrapped_cells` lists the wrapped value cells, by row index in `table`, of the layouts
+    /// that render every field as a row. Like wrapped columns, they are wrapped
+    /// after newline replacement.
+    fn finish(
+        self,
+        mut table: Table,
+        has_header_row: bool,
+        footer_count: usize,
+        wrapped_cells: &[(usize, &WrappedColumn)],
+    ) -> Table {
+        if let Some(padding) = self.padding {
+            table.with(padding);
+        }
+
+        // Remove column headers before adding panel header
+        if self.remove_header_row && has_header_row {
             table.with(Remove::row(Rows::first()));
         }
 
+        let panel_rows = usize::from(self.header.is_some());
         if let Some(header) = self.header {
             table.with(Panel::header(header));
         }
 
+        // Footers are separated from the data rows with the same line as the header row, if any
+        if footer_count > 0 {
+            let mut theme = self.style.theme();
+            if let Some(line) = theme.get_horizontal_line(1).cloned() {
+                theme.insert_horizontal_line(table.count_rows() - footer_count, line);
+                table.with(theme);
+            }
+        }
+
         if let Some(replacement) = self.newline_replacement {
             table.with(
                 Modify::new(Segment::all())
@@ -241,8 +1525,14 @@ impl StyledTable {
             );
         }
 
-        if let Some((col_idx, width)) = self.wrap_column {
-            table.with(Modify::new(Columns::new(col_idx..=col_idx)).with(Width::wrap(width)));
+        // Table layouts resolve column names to indices before getting here
+        for wrapped in &self.wrapped_columns {
+            if let ColumnRef::Index(idx) = wrapped.column {
+                table.with(Modify::new(Columns::new(idx..=idx)).with(wrapped.setting()));
+            }
+        }
+        for &(row, wrapped) in wrapped_cells {
+            table.modify((row + panel_rows, 1), wrapped.setting());
         }
 
         if let Some(width) = self.max_width {
@@ -253,6 +1543,93 @@ impl StyledTable {
     }
 }
 
+/// Shrinks the `columns` in `widths` so that together they take at most `budget` columns,
+/// each proportionally to its width, but not below its minimum width.
+fn shrink_proportionally(
+    widths: &mut [usize],
+    min_widths: &[usize],
+    columns: &[usize],
+    budget: usize,
+) {
+    let mut columns = columns.to_vec();
+    let mut budget = budget;
+
+    loop {
+        let total: usize = columns.iter().map(|&idx| widths[idx]).sum();
+        if total <= budget {
+            return;
+        }
+
+        let share = |idx: usize| widths[idx] * budget / total;
+        let (pinned, rest): (Vec<usize>, Vec<usize>) = columns
+            .iter()
+            .partition(|&&idx| share(idx) < min_widths[idx]);
+        if pinned.is_empty() {
+            let shares: Vec<usize> = columns.iter().map(|&idx| share(idx)).collect();
+            // Columns on the left get the columns lost to rounding down
+            let mut leftover = budget - shares.iter().sum::<usize>();
+            for (&idx, share) in columns.iter().zip(shares) {
+                let extra = usize::from(leftover > 0 && share < widths[idx]);
+                leftover -= extra;
+                widths[idx] = share + extra;
+            }
+            return;
+        }
+
+        for idx in pinned {
+            widths[idx] = min_widths[idx];
+            budget = budget.saturating_sub(min_widths[idx]);
+        }
+        columns = rest;
+    }
+}
+
+/// Size units recognized by [`is_numeric`], compared case-insensitively.
+const SIZE_UNITS: &[&str] = &[
+    "b", "bytes", "k", "kb", "kib", "m", "mb", "mib", "g", "gb", "gib", "t", "tb", "tib", "p",
+    "pb", "pib",
+];
+
+/// Returns true if a cell value is a number, a percentage or a human-readable size,
+/// optionally followed by `/s` for rates.
+fn is_numeric(value: &str) -> bool {
+    let value = strip_ansi(value);
+    let value = value.trim();
+    let value = value.strip_suffix("/s").unwrap_or(value);
+
+    let number_end = value
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | ',' | '_')))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(number_end);
+    let unit = unit.trim_start();
+
+    number.replace([',', '_'], "").parse::<f64>().is_ok()
+        && (unit.is_empty()
+            || unit == "%"
+            || SIZE_UNITS.iter().any(|u| u.eq_ignore_ascii_case(unit)))
+}
+
+/// Returns the indices of the given columns in `headers`, matching case-insensitively.
+///
+/// Unknown columns are skipped.
+fn column_indices(headers: &[String], columns: &[String]) -> Vec<usize> {
+    columns
+        .iter()
+        .filter_map(|col| headers.iter().position(|h| header_eq(h, col)))
+        .collect()
+}
+
+/// Returns true if a column name, usually from [`parse_columns`], refers to `header`.
+///
+/// Names are compared case-insensitively with full Unicode lowercasing,
+/// the same way [`parse_columns`] lowercases them.
+pub(crate) fn header_eq(header: &str, name: &str) -> bool {
+    header
+        .chars()
+        .flat_map(char::to_lowercase)
+        .eq(name.chars().flat_map(char::to_lowercase))
+}
+
 /// Formats an optional value for rendering in a table cell.
 ///
 /// Returns an empty string for None, otherwise the Display representation.
@@ -280,33 +1657,146 @@ pub fn parse_columns(columns_arg: &str) -> Vec<String> {
         .collect()
 }
 
-/// Builds a table with only the specified columns.
+/// Resolves user-provided column names, usually from [`parse_columns`],
+/// to the `Tabled` headers they refer to.
 ///
-/// Columns are matched case-insensitively. Unknown columns are ignored.
-#[must_use]
-pub fn build_table_with_columns<T: tabled::Tabled>(data: &[T], columns: &[String]) -> Table {
-    let mut builder = Builder::default();
+/// Names are matched case-insensitively against the headers and then against
+/// `aliases`, given as `(alias, header)` pairs.
+///
+/// # Errors
+///
+/// Returns [`TableError::UnknownColumns`] listing every name that matches neither,
+/// each with the closest header or alias by edit distance, if there is one.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{parse_columns, resolve_columns};
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Queue {
+///     name: String,
+///     messages: u64,
+/// }
+///
+/// let aliases = [("msgs", "messages")];
+/// let columns = resolve_columns::<Queue>(&parse_columns("msgs,Name"), &aliases).unwrap();
+/// assert_eq!(columns, ["messages", "name"]);
+///
+/// let err = resolve_columns::<Queue>(&parse_columns("nmae,messages"), &aliases).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "unknown column 'nmae' (did you mean 'name'?), expected one of: name, messages"
+/// );
+/// ```
+pub fn resolve_columns<T: tabled::Tabled>(
+    columns: &[String],
+    aliases: &[(&str, &str)],
+) -> Result<Vec<String>, TableError> {
+    let headers: Vec<String> = T::headers().into_iter().map(|h| h.to_string()).collect();
+    let find_header = |name: &str| headers.iter().find(|h| header_eq(h, name));
 
-    let headers: Vec<String> = T::headers()
-        .into_iter()
-        .map(|c| c.to_string().to_lowercase())
-        .collect();
+    let mut resolved = Vec::with_capacity(columns.len());
+    let mut unknown = Vec::new();
+    for column in columns {
+        let header = find_header(column).or_else(|| {
+            aliases
+                .iter()
+                .find(|(alias, _)| header_eq(alias, column))
+                .and_then(|(_, header)| find_header(header))
+        });
+        match header {
+            Some(header) => resolved.push(header.clone()),
+            None => {
+                let candidates = headers
+                    .iter()
+                    .map(String::as_str)
+                    .chain(aliases.iter().map(|(alias, _)| *alias));
+                unknown.push((column.clone(), closest_match(column, candidates)));
+            }
+        }
+    }
 
-    let valid_columns: Vec<(usize, &String)> = columns
-        .iter()
-        .filter_map(|col| headers.iter().position(|h| h == col).map(|idx| (idx, col)))
-        .collect();
+    if unknown.is_empty() {
+        Ok(resolved)
+    } else {
+        Err(TableError::UnknownColumns {
+            unknown,
+            available: headers,
+        })
+    }
+}
 
-    builder.push_record(valid_columns.iter().map(|(_, col)| col.as_str()));
+/// Like [`build_table_with_columns`] but returns an error for unknown columns
+/// instead of ignoring them. See [`resolve_columns`] for how columns and aliases are matched.
+pub fn build_table_with_columns_strict<T: tabled::Tabled>(
+    data: &[T],
+    columns: &[String],
+    aliases: &[(&str, &str)],
+) -> Result<Table, TableError> {
+    let resolved = resolve_columns::<T>(columns, aliases)?;
+    Ok(build_table_with_columns(data, &resolved))
+}
 
-    for item in data {
-        let fields: Vec<String> = item.fields().into_iter().map(|c| c.to_string()).collect();
+/// Returns the candidate closest to `name` by edit distance, if it is close enough
+/// to likely be a typo: at most one edit per three characters.
+fn closest_match<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    let name = name.to_lowercase();
+    let max_distance = name.chars().count().div_ceil(3);
+    candidates
+        .map(|candidate| (edit_distance(&name, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
 
-        let row: Vec<&str> = valid_columns
-            .iter()
-            .map(|(idx, _)| fields[*idx].as_str())
-            .collect();
-        builder.push_record(row);
+/// Returns the optimal string alignment distance between two strings:
+/// the number of insertions, deletions, substitutions and adjacent transpositions.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Builds a table with only the specified columns.
+///
+/// Columns are matched case-insensitively. Unknown columns are ignored,
+/// see [`build_table_with_columns_strict`] for a variant that reports them.
+/// The header row uses the headers as declared by `T::headers()`.
+///
+/// The table is not styled. For table styles, a header panel, wrapping, a maximum width
+/// or display names, use [`StyledTable::columns`] instead.
+#[must_use]
+pub fn build_table_with_columns<T: tabled::Tabled>(data: &[T], columns: &[String]) -> Table {
+    let headers: Vec<String> = T::headers().into_iter().map(|h| h.to_string()).collect();
+    let indices = column_indices(&headers, columns);
+
+    let mut builder = Builder::default();
+    builder.push_record(indices.iter().map(|&idx| headers[idx].clone()));
+    for item in data {
+        let fields = item.fields();
+        builder.push_record(indices.iter().map(|&idx| fields[idx].to_string()));
     }
 
     builder.build()
@@ -0,0 +1,310 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Output themes: the symbols, colors and styles used for each message level.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::sync::RwLock;
+
+use owo_colors::{AnsiColors, OwoColorize, Style};
+
+static THEME: RwLock<Theme> = RwLock::new(Theme::unicode());
+
+/// A terminal color that themes can use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThemeColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl From<ThemeColor> for AnsiColors {
+    fn from(color: ThemeColor) -> Self {
+        match color {
+            ThemeColor::Black => AnsiColors::Black,
+            ThemeColor::Red => AnsiColors::Red,
+            ThemeColor::Green => AnsiColors::Green,
+            ThemeColor::Yellow => AnsiColors::Yellow,
+            ThemeColor::Blue => AnsiColors::Blue,
+            ThemeColor::Magenta => AnsiColors::Magenta,
+            ThemeColor::Cyan => AnsiColors::Cyan,
+            ThemeColor::White => AnsiColors::White,
+            ThemeColor::BrightBlack => AnsiColors::BrightBlack,
+            ThemeColor::BrightRed => AnsiColors::BrightRed,
+            ThemeColor::BrightGreen => AnsiColors::BrightGreen,
+            ThemeColor::BrightYellow => AnsiColors::BrightYellow,
+            ThemeColor::BrightBlue => AnsiColors::BrightBlue,
+            ThemeColor::BrightMagenta => AnsiColors::BrightMagenta,
+            ThemeColor::BrightCyan => AnsiColors::BrightCyan,
+            ThemeColor::BrightWhite => AnsiColors::BrightWhite,
+        }
+    }
+}
+
+/// An optional foreground color combined with text attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TextStyle {
+    pub color: Option<ThemeColor>,
+    pub bold: bool,
+    pub dimmed: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl TextStyle {
+    /// Creates a style with no color and no attributes.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            color: None,
+            bold: false,
+            dimmed: false,
+            italic: false,
+            underline: false,
+        }
+    }
+
+    /// Sets the foreground color.
+    #[must_use]
+    pub const fn color(mut self, color: ThemeColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Makes the text bold.
+    #[must_use]
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Makes the text dimmed.
+    #[must_use]
+    pub const fn dimmed(mut self) -> Self {
+        self.dimmed = true;
+        self
+    }
+
+    /// Makes the text italic.
+    #[must_use]
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Makes the text underlined.
+    #[must_use]
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Returns whether this style changes the appearance of text at all.
+    #[must_use]
+    pub fn is_plain(&self) -> bool {
+        *self == Self::new()
+    }
+
+    /// Renders a value with this style, regardless of whether colors are enabled.
+    ///
+    /// Callers are expected to check [`crate::should_colorize`] or similar first.
+    #[must_use]
+    pub fn paint<T: Display>(&self, value: T) -> String {
+        if self.is_plain() {
+            return value.to_string();
+        }
+        format!("{}", value.style(self.to_owo_style()))
+    }
+
+    fn to_owo_style(self) -> Style {
+        let mut style = Style::new();
+        if let Some(color) = self.color {
+            style = style.color(AnsiColors::from(color));
+        }
+        if self.bold {
+            style = style.bold();
+        }
+        if self.dimmed {
+            style = style.dimmed();
+        }
+        if self.italic {
+            style = style.italic();
+        }
+        if self.underline {
+            style = style.underline();
+        }
+        style
+    }
+}
+
+/// The prefix symbol and style of a message level.
+///
+/// The symbol is rendered with the style. Messages themselves are only styled
+/// for the dimmed level.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelStyle {
+    /// Prefix symbol, can be empty.
+    pub symbol: Cow<'static, str>,
+    pub style: TextStyle,
+}
+
+impl LevelStyle {
+    /// Creates a level style.
+    #[must_use]
+    pub const fn new(symbol: &'static str, style: TextStyle) -> Self {
+        Self {
+            symbol: Cow::Borrowed(symbol),
+            style,
+        }
+    }
+}
+
+/// Symbols, colors and styles for all message levels.
+///
+/// The `format_*` functions only use the level color, so that formatted values
+/// can be embedded into other text.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{Theme, ThemeColor, TextStyle, LevelStyle, set_theme};
+///
+/// let mut theme = Theme::ascii();
+/// theme.info = LevelStyle::new("*", TextStyle::new().color(ThemeColor::Cyan));
+/// set_theme(theme);
+/// # set_theme(Theme::unicode());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    pub success: LevelStyle,
+    pub error: LevelStyle,
+    pub warning: LevelStyle,
+    pub info: LevelStyle,
+    pub dimmed: LevelStyle,
+}
+
+impl Theme {
+    /// The default theme: Unicode symbols (✓, ✗, !, →) and colors.
+    #[must_use]
+    pub const fn unicode() -> Self {
+        Self {
+            success: LevelStyle::new("✓", TextStyle::new().color(ThemeColor::Green).bold()),
+            error: LevelStyle::new("✗", TextStyle::new().color(ThemeColor::Red).bold()),
+            warning: LevelStyle::new("!", TextStyle::new().color(ThemeColor::Yellow).bold()),
+            info: LevelStyle::new("→", TextStyle::new().color(ThemeColor::Blue).bold()),
+            dimmed: LevelStyle::new("", TextStyle::new().dimmed()),
+        }
+    }
+
+    /// ASCII-only symbols (+, x, !, >) with the default colors.
+    ///
+    /// For terminals and consoles that cannot render Unicode symbols.
+    #[must_use]
+    pub const fn ascii() -> Self {
+        Self {
+            success: LevelStyle::new("+", TextStyle::new().color(ThemeColor::Green).bold()),
+            error: LevelStyle::new("x", TextStyle::new().color(ThemeColor::Red).bold()),
+            warning: LevelStyle::new("!", TextStyle::new().color(ThemeColor::Yellow).bold()),
+            info: LevelStyle::new(">", TextStyle::new().color(ThemeColor::Blue).bold()),
+            dimmed: LevelStyle::new("", TextStyle::new().dimmed()),
+        }
+    }
+
+    /// No symbols, colors or styles.
+    #[must_use]
+    pub const fn plain() -> Self {
+        Self {
+            success: LevelStyle::new("", TextStyle::new()),
+            error: LevelStyle::new("", TextStyle::new()),
+            warning: LevelStyle::new("", TextStyle::new()),
+            info: LevelStyle::new("", TextStyle::new()),
+            dimmed: LevelStyle::new("", TextStyle::new()),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
+
+/// Names of the built-in themes, for selecting one from a flag or a configuration file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThemeName {
+    /// See [`Theme::unicode`].
+    #[default]
+    Unicode,
+    /// See [`Theme::ascii`].
+    Ascii,
+    /// See [`Theme::plain`].
+    Plain,
+}
+
+#[cfg(feature = "clap")]
+impl clap::ValueEnum for ThemeName {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Unicode, Self::Ascii, Self::Plain]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(clap::builder::PossibleValue::new(match self {
+            Self::Unicode => "unicode",
+            Self::Ascii => "ascii",
+            Self::Plain => "plain",
+        }))
+    }
+}
+
+impl From<ThemeName> for Theme {
+    fn from(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Unicode => Theme::unicode(),
+            ThemeName::Ascii => Theme::ascii(),
+            ThemeName::Plain => Theme::plain(),
+        }
+    }
+}
+
+/// Sets the process-wide theme used by the `print_*` and `format_*` functions.
+pub fn set_theme(theme: impl Into<Theme>) {
+    let mut current = THEME.write().unwrap_or_else(|e| e.into_inner());
+    *current = theme.into();
+}
+
+/// Returns a copy of the process-wide theme.
+#[must_use]
+pub fn theme() -> Theme {
+    THEME.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
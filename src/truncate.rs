@@ -13,6 +13,22 @@
 // limitations under the License.
 
 //! String truncation utilities that can be used by [`std::fmt::Display`] implementations.
+//!
+//! The `truncate_*_to_width` functions measure terminal display columns instead of
+//! `char`s: CJK characters and most emoji take two columns, combining marks take none.
+//! They never cut inside a grapheme cluster (a family emoji, a flag, a letter with accents).
+//!
+//! The `*_ansi` functions additionally skip ANSI escape sequences (SGR styling, OSC hyperlinks)
+//! when measuring and cutting, so that already colored strings can be truncated safely.
+//!
+//! [`truncate_path`] keeps the file name of a path intact and collapses the directories instead.
+//!
+//! [`Truncated`] truncates while writing into a [`std::fmt::Formatter`], without allocating.
+
+use std::fmt::{self, Write as _};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Default suffix appended to truncated strings.
 pub const DEFAULT_TRUNCATION_SUFFIX: &str = "...";
@@ -94,3 +110,904 @@ pub fn truncate_middle(s: &str, max_chars: usize) -> String {
 
     format!("{}{}{}", start, suffix, end)
 }
+
+/// Returns the number of terminal columns the string occupies.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::display_width;
+///
+/// assert_eq!(display_width("queue"), 5);
+/// assert_eq!(display_width("キュー"), 6);
+/// assert_eq!(display_width("e\u{301}"), 1);
+/// ```
+#[must_use]
+pub fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+/// Truncates a string to a maximum display width, appending the default suffix ("...").
+///
+/// The result, including the suffix, never exceeds `max_width` terminal columns.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::truncate_to_width;
+///
+/// assert_eq!(truncate_to_width("キューの名前", 9), "キュー...");
+/// assert_eq!(truncate_to_width("short", 10), "short");
+/// ```
+#[must_use]
+pub fn truncate_to_width(s: &str, max_width: usize) -> String {
+    truncate_to_width_with_suffix(s, max_width, DEFAULT_TRUNCATION_SUFFIX)
+}
+
+/// Truncates a string to a maximum display width with a custom suffix.
+///
+/// If `max_width` is smaller than the suffix, the suffix itself is truncated.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::truncate_to_width_with_suffix;
+///
+/// assert_eq!(truncate_to_width_with_suffix("👨‍👩‍👧 family", 4, "…"), "👨‍👩‍👧 …");
+/// ```
+#[must_use]
+pub fn truncate_to_width_with_suffix(s: &str, max_width: usize, suffix: &str) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+
+    let suffix_width = display_width(suffix);
+    if max_width <= suffix_width {
+        return take_width(suffix, max_width).to_string();
+    }
+
+    format!("{}{}", take_width(s, max_width - suffix_width), suffix)
+}
+
+/// Truncates a string in the middle to a maximum display width, keeping start and end.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{display_width, truncate_middle_to_width};
+///
+/// let result = truncate_middle_to_width("東京/キュー/注文", 12);
+/// assert!(display_width(&result) <= 12);
+/// assert!(result.starts_with("東京"));
+/// assert!(result.ends_with("注文"));
+/// ```
+#[must_use]
+pub fn truncate_middle_to_width(s: &str, max_width: usize) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+
+    let suffix = DEFAULT_TRUNCATION_SUFFIX;
+    let suffix_width = display_width(suffix);
+    if max_width <= suffix_width {
+        return take_width(suffix, max_width).to_string();
+    }
+
+    let available = max_width - suffix_width;
+    let start = take_width(s, available.div_ceil(2));
+    let end = take_width_from_end(s, available / 2);
+
+    format!("{}{}{}", start, suffix, end)
+}
+
+/// Truncates a string at a word boundary to a maximum display width,
+/// appending the default suffix ("...").
+///
+/// See [`truncate_at_word_with_suffix`].
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::truncate_at_word;
+///
+/// assert_eq!(truncate_at_word("The quick brown fox", 14), "The quick...");
+/// ```
+#[must_use]
+pub fn truncate_at_word(s: &str, max_width: usize) -> String {
+    truncate_at_word_with_suffix(s, max_width, DEFAULT_TRUNCATION_SUFFIX)
+}
+
+/// Truncates a string at a word boundary to a maximum display width with a custom suffix.
+///
+/// The cut is moved back to the last whitespace that fits, and trailing whitespace
+/// is dropped before the suffix. A single word that does not fit is cut like
+/// [`truncate_to_width_with_suffix`] does.
+#[must_use]
+pub fn truncate_at_word_with_suffix(s: &str, max_width: usize, suffix: &str) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+
+    let suffix_width = display_width(suffix);
+    if max_width <= suffix_width {
+        return take_width(suffix, max_width).to_string();
+    }
+
+    let head = take_width(s, max_width - suffix_width);
+    let at_boundary = s[head.len()..].starts_with(char::is_whitespace);
+    let cut = if at_boundary {
+        head
+    } else {
+        head.rfind(char::is_whitespace)
+            .map_or(head, |idx| &head[..idx])
+    };
+    let cut = match cut.trim_end() {
+        "" => head,
+        trimmed => trimmed,
+    };
+
+    format!("{}{}", cut, suffix)
+}
+
+/// Limits a multi-line string to `max_lines` lines, replacing the rest
+/// with a `… (N more lines)` marker line.
+///
+/// To also limit the width, truncate the lines of the result individually.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{truncate_lines, truncate_to_width};
+///
+/// let trace = "error: connection refused\n  at connect\n  at open\n  at main";
+/// assert_eq!(
+///     truncate_lines(trace, 2),
+///     "error: connection refused\n  at connect\n… (2 more lines)"
+/// );
+///
+/// let narrow: Vec<String> = truncate_lines(trace, 1)
+///     .lines()
+///     .map(|line| truncate_to_width(line, 12))
+///     .collect();
+/// assert_eq!(narrow, ["error: co...", "… (3 more..."]);
+/// ```
+#[must_use]
+pub fn truncate_lines(s: &str, max_lines: usize) -> String {
+    match split_lines(s, max_lines) {
+        None => s.to_string(),
+        Some(("", hidden)) => more_lines_marker(hidden),
+        Some((kept, hidden)) => format!("{}\n{}", kept, more_lines_marker(hidden)),
+    }
+}
+
+/// Returns the marker [`truncate_lines`] uses for `hidden` omitted lines.
+#[must_use]
+pub fn more_lines_marker(hidden: usize) -> String {
+    if hidden == 1 {
+        "… (1 more line)".to_string()
+    } else {
+        format!("… ({} more lines)", hidden)
+    }
+}
+
+/// Splits off the first `max_lines` lines of `s`, returning them with the number
+/// of lines that follow, or `None` if `s` has no more than `max_lines` lines.
+pub(crate) fn split_lines(s: &str, max_lines: usize) -> Option<(&str, usize)> {
+    let total = s.lines().count();
+    if total <= max_lines {
+        return None;
+    }
+
+    let end = match max_lines {
+        0 => 0,
+        n => s
+            .match_indices('\n')
+            .nth(n - 1)
+            .map_or(s.len(), |(idx, _)| idx),
+    };
+    let kept = &s[..end];
+    Some((kept.strip_suffix('\r').unwrap_or(kept), total - max_lines))
+}
+
+/// Abbreviates a set of identifiers (hashes, UUIDs, message IDs) to the shortest
+/// common prefix length, no shorter than `min_len`, that keeps them unique within the set,
+/// like `git` does for short commit SHAs.
+///
+/// Duplicate identifiers abbreviate to the same prefix. Identifiers shorter than
+/// the chosen length are kept as is.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::abbreviate_ids;
+///
+/// let ids = ["8dfc6f0a2b", "8dfc7dc7c9", "a164e45f11"];
+/// assert_eq!(abbreviate_ids(&ids, 4), ["8dfc6", "8dfc7", "a164e"]);
+/// assert_eq!(abbreviate_ids(&ids, 7), ["8dfc6f0", "8dfc7dc", "a164e45"]);
+/// ```
+#[must_use]
+pub fn abbreviate_ids<S: AsRef<str>>(ids: &[S], min_len: usize) -> Vec<String> {
+    let mut sorted: Vec<&str> = ids.iter().map(AsRef::as_ref).collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let len = sorted
+        .windows(2)
+        .map(|pair| common_prefix_len(pair[0], pair[1]) + 1)
+        .fold(min_len, usize::max);
+
+    ids.iter()
+        .map(|id| id.as_ref().chars().take(len).collect())
+        .collect()
+}
+
+/// Returns the number of leading `char`s two strings have in common.
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count()
+}
+
+/// Removes ANSI escape sequences (CSI, including SGR styling, and OSC, including hyperlinks).
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::strip_ansi;
+///
+/// assert_eq!(strip_ansi("\x1b[32mrunning\x1b[39m"), "running");
+/// ```
+#[must_use]
+pub fn strip_ansi(s: &str) -> String {
+    AnsiSegments::new(s)
+        .filter(|segment| !segment.is_escape)
+        .map(|segment| segment.text)
+        .collect()
+}
+
+/// Returns the display width of a string, ignoring ANSI escape sequences.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::ansi_display_width;
+///
+/// assert_eq!(ansi_display_width("\x1b[1;31mcrashed\x1b[0m"), 7);
+/// ```
+#[must_use]
+pub fn ansi_display_width(s: &str) -> usize {
+    AnsiSegments::new(s)
+        .filter(|segment| !segment.is_escape)
+        .map(|segment| display_width(segment.text))
+        .sum()
+}
+
+/// Truncates a string that may contain ANSI escape sequences to a maximum display width,
+/// appending the default suffix ("...").
+///
+/// See [`truncate_ansi_with_suffix`].
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::truncate_ansi;
+///
+/// let colored = "\x1b[31mconnection refused\x1b[39m";
+/// assert_eq!(truncate_ansi(colored, 10), "\x1b[31mconnect...\x1b[0m");
+/// ```
+#[must_use]
+pub fn truncate_ansi(s: &str, max_width: usize) -> String {
+    truncate_ansi_with_suffix(s, max_width, DEFAULT_TRUNCATION_SUFFIX)
+}
+
+/// Truncates a string that may contain ANSI escape sequences to a maximum display width
+/// with a custom suffix.
+///
+/// Escape sequences do not count towards the width and are never cut.
+/// The suffix inherits the style active at the cut. If any style or hyperlink
+/// is still open after the suffix, it is closed, so the terminal is not
+/// left in a colored state.
+#[must_use]
+pub fn truncate_ansi_with_suffix(s: &str, max_width: usize, suffix: &str) -> String {
+    if ansi_display_width(s) <= max_width {
+        return s.to_string();
+    }
+
+    let suffix_width = display_width(suffix);
+    if max_width <= suffix_width {
+        return take_width(suffix, max_width).to_string();
+    }
+
+    let mut remaining = max_width - suffix_width;
+    let mut result = String::with_capacity(s.len());
+    let mut style_open = false;
+    let mut link_open = false;
+
+    for segment in AnsiSegments::new(s) {
+        if segment.is_escape {
+            if let Some(is_reset) = sgr_is_reset(segment.text) {
+                style_open = !is_reset;
+            } else if let Some(is_open) = osc8_is_open(segment.text) {
+                link_open = is_open;
+            }
+            result.push_str(segment.text);
+            continue;
+        }
+
+        let taken = take_width(segment.text, remaining);
+        result.push_str(taken);
+        if taken.len() < segment.text.len() {
+            break;
+        }
+        remaining -= display_width(taken);
+    }
+
+    result.push_str(suffix);
+    if style_open {
+        result.push_str("\x1b[0m");
+    }
+    if link_open {
+        result.push_str("\x1b]8;;\x1b\\");
+    }
+    result
+}
+
+/// How [`truncate_path`] collapses directories that do not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathCollapse {
+    /// Replaces the directories in the middle with a single `…` component:
+    /// `/var/…/queues/segment.idx`
+    #[default]
+    Ellipsis,
+    /// Shortens directories to their first character, fish shell-style, starting from the left:
+    /// `/v/l/r/mnesia/segment.idx`. If that is not enough, falls back to [`PathCollapse::Ellipsis`]
+    Abbreviate,
+}
+
+/// Replaces the current user's home directory prefix with `~`.
+///
+/// The home directory is taken from `HOME` (or `USERPROFILE` on Windows).
+#[must_use]
+pub fn abbreviate_home(path: &str) -> String {
+    match home_dir() {
+        Some(home) => replace_home(path, &home),
+        None => path.to_string(),
+    }
+}
+
+/// Truncates a path to a maximum display width, always keeping its final component.
+///
+/// The home directory is abbreviated to `~` first, then directories are collapsed
+/// according to `collapse`. Only when the file name alone does not fit does this
+/// fall back to [`truncate_middle_to_width`].
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::{PathCollapse, truncate_path};
+///
+/// let path = "/var/lib/rabbitmq/mnesia/rabbit@host/queues/segment.idx";
+/// assert_eq!(
+///     truncate_path(path, 32, PathCollapse::Ellipsis),
+///     "/var/…/queues/segment.idx"
+/// );
+/// assert_eq!(
+///     truncate_path(path, 32, PathCollapse::Abbreviate),
+///     "/v/l/r/m/r/queues/segment.idx"
+/// );
+/// ```
+#[must_use]
+pub fn truncate_path(path: &str, max_width: usize, collapse: PathCollapse) -> String {
+    truncate_path_with_home(path, max_width, collapse, home_dir().as_deref())
+}
+
+/// Like [`truncate_path`] but with an explicit home directory (or none).
+#[must_use]
+pub fn truncate_path_with_home(
+    path: &str,
+    max_width: usize,
+    collapse: PathCollapse,
+    home: Option<&str>,
+) -> String {
+    let path = match home {
+        Some(home) => replace_home(path, home),
+        None => path.to_string(),
+    };
+    if display_width(&path) <= max_width {
+        return path;
+    }
+
+    let separator = if path.contains('/') {
+        '/'
+    } else {
+        std::path::MAIN_SEPARATOR
+    };
+    let mut dirs: Vec<String> = path
+        .trim_end_matches(separator)
+        .split(separator)
+        .map(str::to_string)
+        .collect();
+    let file_name = dirs.pop().unwrap_or_default();
+    if dirs.is_empty() || file_name.is_empty() || display_width(&file_name) > max_width {
+        return truncate_middle_to_width(&path, max_width);
+    }
+
+    if collapse == PathCollapse::Abbreviate {
+        for idx in 0..dirs.len() {
+            if dirs[idx].is_empty() || dirs[idx] == "~" {
+                continue;
+            }
+            dirs[idx] = abbreviate_dir(&dirs[idx]);
+            let candidate = join_path(&dirs, &file_name, separator);
+            if display_width(&candidate) <= max_width {
+                return candidate;
+            }
+        }
+    }
+
+    collapse_with_ellipsis(&dirs, &file_name, separator, max_width)
+}
+
+fn home_dir() -> Option<String> {
+    ["HOME", "USERPROFILE"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|home| !home.is_empty())
+}
+
+fn replace_home(path: &str, home: &str) -> String {
+    let home = home.trim_end_matches(['/', '\\']);
+    if home.is_empty() {
+        return path.to_string();
+    }
+    match path.strip_prefix(home) {
+        Some("") => "~".to_string(),
+        Some(rest) if rest.starts_with(['/', '\\']) => format!("~{}", rest),
+        _ => path.to_string(),
+    }
+}
+
+// ".config" becomes ".c" rather than "."
+fn abbreviate_dir(dir: &str) -> String {
+    let skip = usize::from(dir.starts_with('.'));
+    dir.graphemes(true).take(skip + 1).collect()
+}
+
+fn join_path(dirs: &[String], file_name: &str, separator: char) -> String {
+    let mut result = String::new();
+    for dir in dirs {
+        result.push_str(dir);
+        result.push(separator);
+    }
+    result.push_str(file_name);
+    result
+}
+
+// Keeps the root and the first directory of an absolute path (or the first directory
+// of a relative path), then as many directories next to the file name as fit.
+// When that is too wide, keeps only the root, then only the file name
+fn collapse_with_ellipsis(
+    dirs: &[String],
+    file_name: &str,
+    separator: char,
+    max_width: usize,
+) -> String {
+    let head_len = dirs
+        .iter()
+        .position(|dir| !dir.is_empty())
+        .map_or(dirs.len(), |idx| idx + 1);
+    let (head, middle) = dirs.split_at(head_len);
+
+    let build = |tail: &[String]| {
+        let mut parts = head.to_vec();
+        parts.push("…".to_string());
+        parts.extend_from_slice(tail);
+        join_path(&parts, file_name, separator)
+    };
+
+    let mut best = build(&[]);
+    if display_width(&best) > max_width {
+        let root_len = dirs.iter().take_while(|dir| dir.is_empty()).count();
+        let mut parts = dirs[..root_len.min(head_len)].to_vec();
+        parts.push("…".to_string());
+        let short = join_path(&parts, file_name, separator);
+        if display_width(&short) <= max_width {
+            return short;
+        }
+        let relative = format!("…{}{}", separator, file_name);
+        return if display_width(&relative) <= max_width {
+            relative
+        } else {
+            file_name.to_string()
+        };
+    }
+    for kept in 1..middle.len() {
+        let candidate = build(&middle[middle.len() - kept..]);
+        if display_width(&candidate) > max_width {
+            break;
+        }
+        best = candidate;
+    }
+    best
+}
+
+/// Which part of a string [`Truncated`] cuts off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TruncationStrategy {
+    /// Keeps the start: `a long na...`
+    #[default]
+    End,
+    /// Keeps the start and the end: `a lon...name`
+    Middle,
+    /// Keeps the end: `...long name`
+    Start,
+}
+
+/// A [`Display`](fmt::Display) adapter that truncates a string to a display width
+/// as it is written, without allocating.
+///
+/// The maximum width can be set with [`Truncated::max_width`] or with the formatter's
+/// precision (`{:.20}`). When both are present, the smaller one wins. The formatter's
+/// width, fill and alignment flags pad the truncated value, measured in display columns.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::Truncated;
+///
+/// assert_eq!(format!("{:.10}", Truncated::new("a rather long name")), "a rathe...");
+/// assert_eq!(format!("[{:>6.6}]", Truncated::new("ok")), "[    ok]");
+/// assert_eq!(
+///     Truncated::new("/var/lib/rabbitmq/mnesia").max_width(12).middle().to_string(),
+///     "/var/...esia"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Truncated<'a> {
+    value: &'a str,
+    max_width: Option<usize>,
+    strategy: TruncationStrategy,
+    suffix: &'a str,
+}
+
+impl<'a> Truncated<'a> {
+    /// Wraps a string. Without a maximum width or precision, it is written as is.
+    #[must_use]
+    pub const fn new(value: &'a str) -> Self {
+        Self {
+            value,
+            max_width: None,
+            strategy: TruncationStrategy::End,
+            suffix: DEFAULT_TRUNCATION_SUFFIX,
+        }
+    }
+
+    /// Sets the maximum display width.
+    #[must_use]
+    pub const fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets which part of the string is cut off.
+    #[must_use]
+    pub const fn strategy(mut self, strategy: TruncationStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Keeps the start and the end of the string.
+    #[must_use]
+    pub const fn middle(self) -> Self {
+        self.strategy(TruncationStrategy::Middle)
+    }
+
+    /// Keeps the end of the string.
+    #[must_use]
+    pub const fn start(self) -> Self {
+        self.strategy(TruncationStrategy::Start)
+    }
+
+    /// Sets the marker written in place of the cut off part (default "...").
+    #[must_use]
+    pub const fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    // Returns the pieces to write in order: before the marker, the marker, after the marker
+    fn pieces(&self, max_width: Option<usize>) -> [&'a str; 3] {
+        let value = self.value;
+        let Some(max_width) = max_width else {
+            return [value, "", ""];
+        };
+        if display_width(value) <= max_width {
+            return [value, "", ""];
+        }
+
+        let suffix_width = display_width(self.suffix);
+        if max_width <= suffix_width {
+            return ["", take_width(self.suffix, max_width), ""];
+        }
+
+        let available = max_width - suffix_width;
+        match self.strategy {
+            TruncationStrategy::End => [take_width(value, available), self.suffix, ""],
+            TruncationStrategy::Middle => [
+                take_width(value, available.div_ceil(2)),
+                self.suffix,
+                take_width_from_end(value, available / 2),
+            ],
+            TruncationStrategy::Start => ["", self.suffix, take_width_from_end(value, available)],
+        }
+    }
+}
+
+impl fmt::Display for Truncated<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let max_width = match (self.max_width, f.precision()) {
+            (Some(max_width), Some(precision)) => Some(max_width.min(precision)),
+            (max_width, precision) => max_width.or(precision),
+        };
+        let pieces = self.pieces(max_width);
+
+        let width: usize = pieces.iter().map(|piece| display_width(piece)).sum();
+        let padding = f.width().unwrap_or(0).saturating_sub(width);
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+            _ => (0, padding),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        for piece in pieces {
+            f.write_str(piece)?;
+        }
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// A run of either plain text or a single escape sequence.
+struct AnsiSegment<'a> {
+    text: &'a str,
+    is_escape: bool,
+}
+
+/// Splits a string into plain text runs and ANSI escape sequences.
+struct AnsiSegments<'a> {
+    rest: &'a str,
+}
+
+impl<'a> AnsiSegments<'a> {
+    fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+}
+
+impl<'a> Iterator for AnsiSegments<'a> {
+    type Item = AnsiSegment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let len = if self.rest.starts_with('\x1b') {
+            escape_len(self.rest)
+        } else {
+            self.rest.find('\x1b').unwrap_or(self.rest.len())
+        };
+        let (text, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Some(AnsiSegment {
+            text,
+            is_escape: text.starts_with('\x1b'),
+        })
+    }
+}
+
+/// Returns the length in bytes of the escape sequence at the start of `s`.
+///
+/// Unterminated sequences extend to the end of the string.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    match bytes.get(1) {
+        // CSI: parameter and intermediate bytes, then a final byte in 0x40..=0x7E
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map_or(bytes.len(), |pos| pos + 3),
+        // OSC: terminated by BEL or ST (ESC \)
+        Some(b']') => {
+            let mut idx = 2;
+            while idx < bytes.len() {
+                match bytes[idx] {
+                    0x07 => return idx + 1,
+                    0x1b if bytes.get(idx + 1) == Some(&b'\\') => return idx + 2,
+                    _ => idx += 1,
+                }
+            }
+            bytes.len()
+        }
+        // Other two-character escapes
+        Some(_) => 1 + s[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+/// For an SGR sequence, returns whether it resets all attributes.
+fn sgr_is_reset(escape: &str) -> Option<bool> {
+    let params = escape.strip_prefix("\x1b[")?.strip_suffix('m')?;
+    Some(params.is_empty() || params.split(';').all(|p| p.is_empty() || p == "0"))
+}
+
+/// For an OSC 8 hyperlink sequence, returns whether it opens (rather than closes) a link.
+fn osc8_is_open(escape: &str) -> Option<bool> {
+    let body = escape.strip_prefix("\x1b]8;")?;
+    let body = body
+        .strip_suffix('\x07')
+        .or_else(|| body.strip_suffix("\x1b\\"))?;
+    let (_params, uri) = body.split_once(';')?;
+    Some(!uri.is_empty())
+}
+
+/// Returns the longest prefix of `s` that fits into `max_width` columns.
+pub(crate) fn take_width(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (idx, grapheme) in s.grapheme_indices(true) {
+        width += grapheme.width();
+        if width > max_width {
+            return &s[..idx];
+        }
+    }
+    s
+}
+
+/// Returns the longest suffix of `s` that fits into `max_width` columns.
+fn take_width_from_end(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (idx, grapheme) in s.grapheme_indices(true).rev() {
+        width += grapheme.width();
+        if width > max_width {
+            return &s[idx + grapheme.len()..];
+        }
+    }
+    s
+}
+
+/// Returns the longest prefix of `s` that fits into `max_width` columns.
+/// ANSI escape sequences do not count towards the width and are never cut.
+pub(crate) fn take_ansi_width(s: &str, max_width: usize) -> &str {
+    let mut remaining = max_width;
+    let mut end = 0;
+    for segment in AnsiSegments::new(s) {
+        if segment.is_escape {
+            end += segment.text.len();
+            continue;
+        }
+
+        let taken = take_width(segment.text, remaining);
+        end += taken.len();
+        if taken.len() < segment.text.len() {
+            break;
+        }
+        remaining -= display_width(taken);
+    }
+    &s[..end]
+}
+
+/// The styles and hyperlink that are open after a run of text with ANSI escape sequences,
+/// for closing them at the end of a line and reopening them on the next one.
+#[derive(Debug, Default)]
+pub(crate) struct AnsiState {
+    /// SGR attributes by category (foreground, bold, ...), with their parameters.
+    attributes: Vec<(u8, String)>,
+    link: Option<String>,
+}
+
+impl AnsiState {
+    /// Updates the state with the escape sequences in `text`.
+    pub(crate) fn update(&mut self, text: &str) {
+        for segment in AnsiSegments::new(text).filter(|segment| segment.is_escape) {
+            if let Some(params) = segment
+                .text
+                .strip_prefix("\x1b[")
+                .and_then(|rest| rest.strip_suffix('m'))
+            {
+                self.update_sgr(params);
+            } else if let Some(is_open) = osc8_is_open(segment.text) {
+                self.link = is_open.then(|| segment.text.to_string());
+            }
+        }
+    }
+
+    fn update_sgr(&mut self, params: &str) {
+        let params: Vec<&str> = params.split(';').collect();
+        let mut idx = 0;
+        while idx < params.len() {
+            let code: u8 = match params[idx] {
+                "" => 0,
+                param => match param.parse() {
+                    Ok(code) => code,
+                    Err(_) => {
+                        idx += 1;
+                        continue;
+                    }
+                },
+            };
+            // Extended colors take their parameters along: `38;5;n` and `38;2;r;g;b`
+            let len = match (code, params.get(idx + 1)) {
+                (38 | 48 | 58, Some(&"5")) => 3,
+                (38 | 48 | 58, Some(&"2")) => 5,
+                _ => 1,
+            };
+            let end = (idx + len).min(params.len());
+
+            if code == 0 {
+                self.attributes.clear();
+            } else if let Some(categories) = sgr_resets(code) {
+                self.attributes
+                    .retain(|(category, _)| !categories.contains(category));
+            } else if let Some(category) = sgr_category(code) {
+                self.attributes.retain(|(c, _)| *c != category);
+                self.attributes.push((category, params[idx..end].join(";")));
+            }
+            idx = end;
+        }
+    }
+
+    /// Returns the escape sequences that close the open styles and hyperlink.
+    pub(crate) fn close(&self) -> String {
+        let mut closing = String::new();
+        if !self.attributes.is_empty() {
+            closing.push_str("\x1b[0m");
+        }
+        if self.link.is_some() {
+            closing.push_str("\x1b]8;;\x1b\\");
+        }
+        closing
+    }
+
+    /// Returns the escape sequences that reopen the open styles and hyperlink.
+    pub(crate) fn reopen(&self) -> String {
+        let mut opening = self.link.clone().unwrap_or_default();
+        if !self.attributes.is_empty() {
+            let params: Vec<&str> = self.attributes.iter().map(|(_, p)| p.as_str()).collect();
+            opening.push_str(&format!("\x1b[{}m", params.join(";")));
+        }
+        opening
+    }
+}
+
+/// Returns the category of an SGR attribute that [`AnsiState`] tracks.
+fn sgr_category(code: u8) -> Option<u8> {
+    match code {
+        1..=4 | 7..=9 => Some(code),
+        5 | 6 => Some(5),
+        30..=38 | 90..=97 => Some(30),
+        40..=48 | 100..=107 => Some(40),
+        58 => Some(58),
+        _ => None,
+    }
+}
+
+/// Returns the categories an SGR code turns off, for codes such as `39` (default foreground).
+fn sgr_resets(code: u8) -> Option<&'static [u8]> {
+    match code {
+        22 => Some(&[1, 2]),
+        23 => Some(&[3]),
+        24 => Some(&[4]),
+        25 => Some(&[5]),
+        27 => Some(&[7]),
+        28 => Some(&[8]),
+        29 => Some(&[9]),
+        39 => Some(&[30]),
+        49 => Some(&[40]),
+        59 => Some(&[58]),
+        _ => None,
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Text wrapping for prose: long error explanations, help-like output, descriptions.
+//!
+//! Widths are measured in terminal display columns, ignoring ANSI escape sequences,
+//! the same way table cells are measured.
+
+use crate::truncate::{AnsiState, ansi_display_width, take_ansi_width};
+
+/// Wraps text at word boundaries to a maximum display width.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::TextWrapper;
+///
+/// let lines = TextWrapper::new()
+///     .width(30)
+///     .first_line_prefix("Error: ")
+///     .hanging_indent(7)
+///     .wrap("the queue could not be declared because it already exists");
+///
+/// assert_eq!(
+///     lines,
+///     vec![
+///         "Error: the queue could not be",
+///         "       declared because it",
+///         "       already exists",
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TextWrapper {
+    width: Option<usize>,
+    first_line_prefix: String,
+    hanging_indent: usize,
+}
+
+impl TextWrapper {
+    /// Creates a wrapper that wraps at the terminal width, without a prefix or indent.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum width in columns, including the prefix and indent.
+    ///
+    /// Defaults to `terminal_width()` when the `tables` feature is enabled.
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets a prefix for the first line, e.g. `"Error: "` or `"  * "`.
+    #[must_use]
+    pub fn first_line_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.first_line_prefix = prefix.into();
+        self
+    }
+
+    /// Indents every line after the first by `columns` spaces.
+    ///
+    /// Use the display width of the first line prefix to align
+    /// continuation lines with the text after it.
+    #[must_use]
+    pub fn hanging_indent(mut self, columns: usize) -> Self {
+        self.hanging_indent = columns;
+        self
+    }
+
+    /// Wraps `text` into lines.
+    ///
+    /// Existing newlines are preserved. Runs of whitespace within a line are
+    /// collapsed into a single space. Words wider than the available width
+    /// are broken between grapheme clusters, never inside ANSI escape sequences.
+    /// Styles and hyperlinks that are open at the end of a line are closed there
+    /// and reopened on the next line.
+    ///
+    /// The prefix and indent are shortened to leave at least one column for the text.
+    #[must_use]
+    pub fn wrap(&self, text: &str) -> Vec<String> {
+        let width = self.width.unwrap_or_else(default_width).max(1);
+        let indent_width = self.hanging_indent.min(width - 1);
+        let indent = " ".repeat(indent_width);
+        let prefix = take_ansi_width(&self.first_line_prefix, width - 1);
+        let mut state = AnsiState::default();
+        state.update(prefix);
+        let mut lines = Vec::new();
+
+        for paragraph in text.lines() {
+            let mut line = if lines.is_empty() {
+                prefix.to_string()
+            } else {
+                start_line(&indent, &state)
+            };
+            let mut line_width = ansi_display_width(&line);
+            let mut has_words = false;
+
+            for word in paragraph.split_whitespace() {
+                let word_width = ansi_display_width(word);
+                if has_words && line_width + 1 + word_width > width {
+                    let next = start_line(&indent, &state);
+                    lines.push(end_line(std::mem::replace(&mut line, next), &state));
+                    line_width = indent_width;
+                    has_words = false;
+                }
+                if has_words {
+                    line.push(' ');
+                    line_width += 1;
+                }
+
+                let mut rest = word;
+                while !rest.is_empty() && line_width + ansi_display_width(rest) > width {
+                    let available = width.saturating_sub(line_width).max(1);
+                    let chunk = match take_ansi_width(rest, available) {
+                        // A wide grapheme that does not fit at all still has to go somewhere
+                        chunk if ansi_display_width(chunk) == 0 => take_ansi_width(rest, 2),
+                        chunk => chunk,
+                    };
+                    line.push_str(chunk);
+                    state.update(chunk);
+                    let next = start_line(&indent, &state);
+                    lines.push(end_line(std::mem::replace(&mut line, next), &state));
+                    line_width = indent_width;
+                    rest = &rest[chunk.len()..];
+                }
+                line.push_str(rest);
+                state.update(rest);
+                line_width += ansi_display_width(rest);
+                has_words = !rest.is_empty();
+            }
+
+            lines.push(end_line(line, &state));
+        }
+
+        lines
+    }
+
+    /// Wraps `text` and joins the lines with newlines.
+    #[must_use]
+    pub fn fill(&self, text: &str) -> String {
+        self.wrap(text).join("\n")
+    }
+}
+
+/// Wraps text at word boundaries to `width` display columns.
+///
+/// # Example
+///
+/// ```
+/// use bel7_cli::wrap;
+///
+/// assert_eq!(wrap("one two three four", 9), vec!["one two", "three", "four"]);
+/// ```
+#[must_use]
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    TextWrapper::new().width(width).wrap(text)
+}
+
+/// Wraps text at word boundaries to `width` display columns and joins the lines with newlines.
+#[must_use]
+pub fn fill(text: &str, width: usize) -> String {
+    TextWrapper::new().width(width).fill(text)
+}
+
+/// Starts a continuation line: the indent, then the styles that are still open.
+fn start_line(indent: &str, state: &AnsiState) -> String {
+    format!("{}{}", indent, state.reopen())
+}
+
+/// Ends a line: trims trailing whitespace and closes the styles that are still open.
+fn end_line(mut line: String, state: &AnsiState) -> String {
+    line.truncate(line.trim_end().len());
+    line.push_str(&state.close());
+    line
+}
+
+#[cfg(feature = "tables")]
+fn default_width() -> usize {
+    crate::tables::terminal_width()
+}
+
+// Without the `tables` feature there is no terminal size detection,
+// so this uses the same fallback as `DEFAULT_TERMINAL_WIDTH`
+#[cfg(not(feature = "tables"))]
+fn default_width() -> usize {
+    120
+}
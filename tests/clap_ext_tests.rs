@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use bel7_cli::ArgMatchesExt;
-use clap::{Arg, Command};
+#![cfg(feature = "clap")]
+
+use bel7_cli::{ArgMatchesExt, ColorChoice, ThemeName, Verbosity, quiet_arg, verbose_arg};
+use clap::{Arg, Command, ValueEnum};
 
 fn test_command() -> Command {
     Command::new("test")
@@ -147,3 +149,71 @@ fn test_get_typed_or_present() {
     let matches = cmd.get_matches_from(["test", "--count", "42"]);
     assert_eq!(matches.get_typed_or::<i32>("count", 99), 42);
 }
+
+#[test]
+fn test_color_choice_value_enum() {
+    assert_eq!(
+        ColorChoice::from_str("always", true).unwrap(),
+        ColorChoice::Always
+    );
+    assert_eq!(
+        ColorChoice::from_str("never", true).unwrap(),
+        ColorChoice::Never
+    );
+    assert_eq!(
+        ColorChoice::from_str("auto", true).unwrap(),
+        ColorChoice::Auto
+    );
+    assert!(ColorChoice::from_str("sometimes", true).is_err());
+}
+
+#[test]
+fn test_theme_name_value_enum() {
+    assert_eq!(
+        ThemeName::from_str("ascii", true).unwrap(),
+        ThemeName::Ascii
+    );
+    assert_eq!(
+        ThemeName::from_str("plain", true).unwrap(),
+        ThemeName::Plain
+    );
+    assert!(ThemeName::from_str("fancy", true).is_err());
+}
+
+fn verbosity_command() -> Command {
+    Command::new("test")
+        .arg(verbose_arg())
+        .arg(quiet_arg())
+        .subcommand(Command::new("list"))
+}
+
+#[test]
+fn test_verbosity_default() {
+    let matches = verbosity_command().get_matches_from(["test"]);
+    assert_eq!(matches.verbosity(), Verbosity::Normal);
+}
+
+#[test]
+fn test_verbosity_repeated_verbose() {
+    let matches = verbosity_command().get_matches_from(["test", "-vv"]);
+    assert_eq!(matches.verbosity(), Verbosity::Debug);
+}
+
+#[test]
+fn test_verbosity_quiet() {
+    let matches = verbosity_command().get_matches_from(["test", "--quiet"]);
+    assert_eq!(matches.verbosity(), Verbosity::Quiet);
+}
+
+#[test]
+fn test_verbosity_global_on_subcommand() {
+    let matches = verbosity_command().get_matches_from(["test", "list", "-v"]);
+    let (_, sub_matches) = matches.subcommand().unwrap();
+    assert_eq!(sub_matches.verbosity(), Verbosity::Verbose);
+}
+
+#[test]
+fn test_verbosity_without_args_defined() {
+    let matches = test_command().get_matches_from(["test", "--name", "foo"]);
+    assert_eq!(matches.verbosity(), Verbosity::Normal);
+}
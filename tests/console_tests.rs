@@ -0,0 +1,206 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_cli::{ColorChoice, Console, MessageLevel, OutputStream, StreamRouting, Verbosity};
+
+fn plain_console() -> Console<Vec<u8>, Vec<u8>> {
+    Console::new(Vec::new(), Vec::new()).with_color_choice(ColorChoice::Never)
+}
+
+fn into_strings(console: Console<Vec<u8>, Vec<u8>>) -> (String, String) {
+    let (out, err) = console.into_parts();
+    (
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    )
+}
+
+#[test]
+fn test_console_success_writes_to_out() {
+    let mut console = plain_console();
+    console.success("done").unwrap();
+    let (out, err) = into_strings(console);
+    assert_eq!(out, "✓ done\n");
+    assert!(err.is_empty());
+}
+
+#[test]
+fn test_console_error_writes_to_err() {
+    let mut console = plain_console();
+    console.error("failed").unwrap();
+    let (out, err) = into_strings(console);
+    assert!(out.is_empty());
+    assert_eq!(err, "✗ failed\n");
+}
+
+#[test]
+fn test_console_all_levels() {
+    let mut console = plain_console();
+    console.success("a").unwrap();
+    console.warning("b").unwrap();
+    console.info("c").unwrap();
+    console.dimmed("d").unwrap();
+    console.error("e").unwrap();
+    let (out, err) = into_strings(console);
+    assert_eq!(out, "✓ a\n! b\n→ c\nd\n");
+    assert_eq!(err, "✗ e\n");
+}
+
+#[test]
+fn test_console_always_colorizes() {
+    let mut console = Console::new(Vec::new(), Vec::new()).with_color_choice(ColorChoice::Always);
+    assert!(console.colorize_out());
+    assert!(console.colorize_err());
+    console.success("done").unwrap();
+    console.error("failed").unwrap();
+    let (out, err) = into_strings(console);
+    assert!(out.contains("\x1b["));
+    assert!(out.contains("done"));
+    assert!(err.contains("\x1b["));
+}
+
+#[test]
+fn test_console_never_colorizes() {
+    let console = plain_console();
+    assert!(!console.colorize_out());
+    assert!(!console.colorize_err());
+}
+
+#[test]
+fn test_console_out_accessor() {
+    use std::io::Write;
+
+    let mut console = plain_console();
+    writeln!(console.out(), "raw").unwrap();
+    let (out, _) = into_strings(console);
+    assert_eq!(out, "raw\n");
+}
+
+#[test]
+fn test_console_stdio_does_not_panic() {
+    let mut console = Console::stdio();
+    let _ = console.dimmed("stdio");
+}
+
+#[test]
+fn test_console_quiet_suppresses_regular_output() {
+    let mut console = plain_console().with_verbosity(Verbosity::Quiet);
+    console.success("a").unwrap();
+    console.info("b").unwrap();
+    console.dimmed("c").unwrap();
+    console.warning("d").unwrap();
+    console.error("e").unwrap();
+    let (out, err) = into_strings(console);
+    assert_eq!(out, "! d\n");
+    assert_eq!(err, "✗ e\n");
+}
+
+#[test]
+fn test_console_normal_suppresses_verbose_output() {
+    let mut console = plain_console().with_verbosity(Verbosity::Normal);
+    console.verbose("a").unwrap();
+    console.debug("b").unwrap();
+    console.trace("c").unwrap();
+    let (out, _) = into_strings(console);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_console_debug_verbosity() {
+    let mut console = plain_console().with_verbosity(Verbosity::Debug);
+    console.verbose("a").unwrap();
+    console.debug("b").unwrap();
+    console.trace("c").unwrap();
+    let (out, _) = into_strings(console);
+    assert_eq!(out, "→ a\nb\n");
+}
+
+#[test]
+fn test_console_trace_verbosity() {
+    let mut console = plain_console().with_verbosity(Verbosity::Trace);
+    console.trace("c").unwrap();
+    let (out, _) = into_strings(console);
+    assert_eq!(out, "c\n");
+}
+
+#[test]
+fn test_console_default_stream_routing() {
+    let console = plain_console();
+    assert_eq!(console.stream_routing(), StreamRouting::default());
+}
+
+#[test]
+fn test_console_all_to_stderr() {
+    let mut console = plain_console()
+        .with_verbosity(Verbosity::Trace)
+        .with_stream_routing(StreamRouting::all_to_stderr());
+    console.success("a").unwrap();
+    console.warning("b").unwrap();
+    console.info("c").unwrap();
+    console.verbose("d").unwrap();
+    console.error("e").unwrap();
+    let (out, err) = into_strings(console);
+    assert!(out.is_empty());
+    assert_eq!(err, "✓ a\n! b\n→ c\n→ d\n✗ e\n");
+}
+
+#[test]
+fn test_console_route_single_level() {
+    let routing = StreamRouting::new().route(MessageLevel::Warning, OutputStream::Stderr);
+    let mut console = plain_console().with_stream_routing(routing);
+    console.warning("careful").unwrap();
+    console.info("fyi").unwrap();
+    let (out, err) = into_strings(console);
+    assert_eq!(out, "→ fyi\n");
+    assert_eq!(err, "! careful\n");
+}
+
+#[test]
+fn test_console_route_debug_to_stderr() {
+    let routing = StreamRouting::new()
+        .route(MessageLevel::Debug, OutputStream::Stderr)
+        .route(MessageLevel::Trace, OutputStream::Stderr);
+    let mut console = plain_console()
+        .with_verbosity(Verbosity::Trace)
+        .with_stream_routing(routing);
+    console.debug("d").unwrap();
+    console.trace("t").unwrap();
+    console.dimmed("x").unwrap();
+    let (out, err) = into_strings(console);
+    assert_eq!(out, "x\n");
+    assert_eq!(err, "d\nt\n");
+}
+
+#[test]
+fn test_stream_routing_stream_for() {
+    let routing = StreamRouting::new();
+    assert_eq!(
+        routing.stream_for(MessageLevel::Error),
+        OutputStream::Stderr
+    );
+    assert_eq!(
+        routing.stream_for(MessageLevel::Warning),
+        OutputStream::Stdout
+    );
+
+    let routing = StreamRouting::all_to_stderr();
+    assert_eq!(
+        routing.stream_for(MessageLevel::Success),
+        OutputStream::Stderr
+    );
+    assert_eq!(
+        routing.stream_for(MessageLevel::Trace),
+        OutputStream::Stderr
+    );
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg(feature = "errors")]
+
 use bel7_cli::{ExitCode, ExitCodeProvider};
 use thiserror::Error;
 
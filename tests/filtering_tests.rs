@@ -0,0 +1,206 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "filtering")]
+
+use bel7_cli::{Filter, FilterOp, TableError, filter_records, parse_filters};
+use tabled::Tabled;
+
+#[derive(Tabled, Debug, Clone, PartialEq)]
+struct QueueRow {
+    name: String,
+    state: String,
+    messages: String,
+}
+
+fn rows() -> Vec<QueueRow> {
+    [
+        ("orders.eu", "running", "5,000"),
+        ("orders.us", "running", "10"),
+        ("orders.ap", "crashed", "9000"),
+        ("events", "running", "2000"),
+        ("audit", "idle", ""),
+    ]
+    .into_iter()
+    .map(|(name, state, messages)| QueueRow {
+        name: name.into(),
+        state: state.into(),
+        messages: messages.into(),
+    })
+    .collect()
+}
+
+fn filtered(expressions: &[&str]) -> Vec<String> {
+    let mut data = rows();
+    filter_records(&mut data, &parse_filters(expressions).unwrap()).unwrap();
+    data.into_iter().map(|r| r.name).collect()
+}
+
+#[test]
+fn test_filter_parse() {
+    let filter = Filter::parse(" State != running ").unwrap();
+    assert_eq!(filter.column(), "state");
+    assert_eq!(filter.op(), FilterOp::Ne);
+    assert_eq!(filter.value(), "running");
+    assert_eq!(filter.op().as_str(), "!=");
+}
+
+#[test]
+fn test_filter_parse_all_operators() {
+    for (expression, op) in [
+        ("a=1", FilterOp::Eq),
+        ("a!=1", FilterOp::Ne),
+        ("a>1", FilterOp::Gt),
+        ("a>=1", FilterOp::Ge),
+        ("a<1", FilterOp::Lt),
+        ("a<=1", FilterOp::Le),
+        ("a~1", FilterOp::Matches),
+        ("a!~1", FilterOp::NotMatches),
+    ] {
+        assert_eq!(Filter::parse(expression).unwrap().op(), op, "{expression}");
+    }
+}
+
+#[test]
+fn test_filter_equality() {
+    assert_eq!(
+        filtered(&["state=running"]),
+        ["orders.eu", "orders.us", "events"]
+    );
+    assert_eq!(filtered(&["state!=running"]), ["orders.ap", "audit"]);
+}
+
+#[test]
+fn test_filter_numeric_equality() {
+    let eq: Filter = "messages=1000".parse().unwrap();
+    assert!(eq.matches("1,000"));
+    assert!(eq.matches("1000.0"));
+    assert!(!eq.matches("100"));
+
+    let ne: Filter = "messages!=1000".parse().unwrap();
+    assert!(!ne.matches("1,000"));
+    assert!(ne.matches("999"));
+
+    let state: Filter = "state=Running".parse().unwrap();
+    assert!(!state.matches("running"));
+}
+
+#[test]
+fn test_filter_versions() {
+    let eq: Filter = "version=3.10".parse().unwrap();
+    assert!(eq.matches("3.10"));
+    assert!(!eq.matches("3.1"));
+    assert!(!eq.matches("3.10.0"));
+
+    let ne: Filter = "version!=3.10".parse().unwrap();
+    assert!(ne.matches("3.1"));
+
+    let gt: Filter = "version>3.9".parse().unwrap();
+    assert!(gt.matches("3.12"));
+    assert!(gt.matches("3.9.1"));
+    assert!(!gt.matches("3.8.4"));
+
+    let le: Filter = "version<=3.13.1".parse().unwrap();
+    assert!(le.matches("3.13"));
+    assert!(!le.matches("3.13.10"));
+}
+
+#[test]
+fn test_filter_numeric_comparisons() {
+    assert_eq!(
+        filtered(&["messages>1000"]),
+        ["orders.eu", "orders.ap", "events"]
+    );
+    assert_eq!(filtered(&["messages<=10"]), ["orders.us", "audit"]);
+    assert_eq!(
+        filtered(&["messages>=2000", "messages<9000"]),
+        ["orders.eu", "events"]
+    );
+}
+
+#[test]
+fn test_filter_regex_and_substring() {
+    assert_eq!(
+        filtered(&[r"name~^orders\."]),
+        ["orders.eu", "orders.us", "orders.ap"]
+    );
+    assert_eq!(filtered(&["name~ent"]), ["events"]);
+    assert_eq!(filtered(&["name!~orders"]), ["events", "audit"]);
+}
+
+#[test]
+fn test_filter_and_combined() {
+    assert_eq!(
+        filtered(&["state=running", "messages>1000", r"name~^orders\."]),
+        ["orders.eu"]
+    );
+}
+
+#[test]
+fn test_filter_unknown_column() {
+    let mut data = rows();
+    let filters = parse_filters(&["size>10"]).unwrap();
+    let err = filter_records(&mut data, &filters).unwrap_err();
+    assert_eq!(
+        err,
+        TableError::UnknownFilterColumn {
+            column: "size".into(),
+            available: vec!["name".into(), "state".into(), "messages".into()],
+        }
+    );
+    assert_eq!(data, rows());
+}
+
+#[test]
+fn test_filter_non_ascii_headers() {
+    #[derive(Tabled)]
+    struct LocalizedQueue {
+        name: &'static str,
+        #[tabled(rename = "État")]
+        state: &'static str,
+    }
+
+    let mut data = vec![
+        LocalizedQueue {
+            name: "a",
+            state: "ok",
+        },
+        LocalizedQueue {
+            name: "b",
+            state: "failed",
+        },
+    ];
+    filter_records(&mut data, &parse_filters(&["État=ok"]).unwrap()).unwrap();
+    assert_eq!(data.len(), 1);
+    assert_eq!(data[0].name, "a");
+}
+
+#[test]
+fn test_filter_malformed_expressions() {
+    let err = Filter::parse("running").unwrap_err();
+    assert!(matches!(err, TableError::InvalidFilter { .. }));
+    assert!(
+        err.to_string()
+            .starts_with("invalid filter 'running': expected an operator")
+    );
+
+    let err = "=running".parse::<Filter>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid filter '=running': expected a column name before the operator"
+    );
+
+    assert!(Filter::parse("state!running").is_err());
+    assert!(Filter::parse("name~(orders").is_err());
+}
@@ -0,0 +1,236 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "formats")]
+
+use bel7_cli::{OutputFormat, RecordRenderer, TableStyle, parse_columns};
+use serde::Serialize;
+use tabled::Tabled;
+
+#[derive(Tabled, Serialize)]
+struct Queue {
+    name: String,
+    vhost: String,
+    messages: u64,
+}
+
+#[derive(Tabled, Serialize)]
+struct RenamedQueue {
+    #[tabled(rename = "Queue Name")]
+    #[serde(rename = "queue_name")]
+    name: String,
+    #[tabled(rename = "Messages")]
+    messages: u64,
+}
+
+#[derive(Tabled, Serialize)]
+struct PartiallySkippedQueue {
+    #[tabled(rename = "Messages Ready")]
+    #[serde(rename = "messages_ready")]
+    messages: u64,
+    #[tabled(skip)]
+    internal_id: u64,
+    #[serde(skip)]
+    display_only: String,
+}
+
+fn queues() -> Vec<Queue> {
+    vec![
+        Queue {
+            name: "orders".into(),
+            vhost: "/".into(),
+            messages: 12,
+        },
+        Queue {
+            name: "events, archived".into(),
+            vhost: "prod".into(),
+            messages: 0,
+        },
+    ]
+}
+
+#[test]
+fn test_output_format_default() {
+    assert_eq!(OutputFormat::default(), OutputFormat::Table);
+}
+
+#[test]
+fn test_render_table() {
+    let output = RecordRenderer::new(OutputFormat::Table)
+        .style(TableStyle::Markdown)
+        .render(&queues())
+        .unwrap();
+    assert!(output.contains("| name"));
+    assert!(output.contains("orders"));
+}
+
+#[test]
+fn test_render_table_with_columns() {
+    let output = RecordRenderer::new(OutputFormat::Table)
+        .style(TableStyle::Ascii)
+        .columns(parse_columns("messages"))
+        .render(&queues())
+        .unwrap();
+    assert!(output.contains("12"));
+    assert!(output.contains("+"));
+    assert!(!output.contains("orders"));
+}
+
+#[test]
+fn test_render_json() {
+    let output = RecordRenderer::new(OutputFormat::Json)
+        .render(&queues())
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(value[0]["name"], "orders");
+    assert_eq!(value[0]["messages"], 12);
+    assert_eq!(value.as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_render_json_with_columns() {
+    let output = RecordRenderer::new(OutputFormat::Json)
+        .columns(parse_columns("messages,name"))
+        .render(&queues())
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let first = value[0].as_object().unwrap();
+    assert_eq!(first.keys().collect::<Vec<_>>(), vec!["messages", "name"]);
+}
+
+#[test]
+fn test_render_json_with_renamed_headers() {
+    let data = vec![RenamedQueue {
+        name: "orders".into(),
+        messages: 7,
+    }];
+    let output = RecordRenderer::new(OutputFormat::Json)
+        .columns(parse_columns("messages,queue name"))
+        .render(&data)
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let first = value[0].as_object().unwrap();
+    assert_eq!(
+        first.keys().collect::<Vec<_>>(),
+        vec!["messages", "queue_name"]
+    );
+    assert_eq!(first["messages"], 7);
+}
+
+#[test]
+fn test_render_with_columns_skipped_by_tabled_or_serde() {
+    let data = vec![PartiallySkippedQueue {
+        messages: 7,
+        internal_id: 42,
+        display_only: "orders on node-1".into(),
+    }];
+    let columns = parse_columns("messages ready,display_only");
+
+    let csv = RecordRenderer::new(OutputFormat::Csv)
+        .columns(columns.clone())
+        .render(&data)
+        .unwrap();
+    assert_eq!(csv, "Messages Ready,display_only\n7,orders on node-1");
+
+    for format in [OutputFormat::Json, OutputFormat::Yaml] {
+        let output = RecordRenderer::new(format)
+            .columns(columns.clone())
+            .render(&data)
+            .unwrap();
+        assert!(output.contains("messages_ready"), "{output}");
+        assert!(!output.contains("internal_id"), "{output}");
+        assert!(!output.contains("display_only"), "{output}");
+    }
+}
+
+#[test]
+fn test_render_json_empty() {
+    let data: Vec<Queue> = vec![];
+    let output = RecordRenderer::new(OutputFormat::Json)
+        .render(&data)
+        .unwrap();
+    assert_eq!(output, "[]");
+}
+
+#[test]
+fn test_render_yaml() {
+    let output = RecordRenderer::new(OutputFormat::Yaml)
+        .columns(parse_columns("name"))
+        .render(&queues())
+        .unwrap();
+    assert!(output.contains("- name: orders"));
+    assert!(!output.contains("messages"));
+    assert!(!output.ends_with('\n'));
+}
+
+#[test]
+fn test_render_csv() {
+    let output = RecordRenderer::new(OutputFormat::Csv)
+        .render(&queues())
+        .unwrap();
+    assert_eq!(
+        output,
+        "name,vhost,messages\norders,/,12\n\"events, archived\",prod,0"
+    );
+}
+
+#[test]
+fn test_render_csv_with_columns() {
+    let output = RecordRenderer::new(OutputFormat::Csv)
+        .columns(parse_columns("messages,name"))
+        .render(&queues())
+        .unwrap();
+    assert_eq!(output, "messages,name\n12,orders\n0,\"events, archived\"");
+}
+
+#[test]
+fn test_render_csv_uses_tabled_headers() {
+    let data = vec![RenamedQueue {
+        name: "orders".into(),
+        messages: 7,
+    }];
+    let output = RecordRenderer::new(OutputFormat::Csv)
+        .render(&data)
+        .unwrap();
+    assert_eq!(output, "Queue Name,Messages\norders,7");
+}
+
+#[test]
+fn test_render_tsv_escapes_tabs_and_newlines() {
+    let data = vec![Queue {
+        name: "a\tb".into(),
+        vhost: "line1\nline2".into(),
+        messages: 1,
+    }];
+    let output = RecordRenderer::new(OutputFormat::Tsv)
+        .render(&data)
+        .unwrap();
+    assert_eq!(output, "name\tvhost\tmessages\na\\tb\tline1\\nline2\t1");
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn test_output_format_value_enum() {
+    use clap::ValueEnum;
+
+    assert_eq!(
+        OutputFormat::from_str("json", true).unwrap(),
+        OutputFormat::Json
+    );
+    assert_eq!(
+        OutputFormat::from_str("TSV", true).unwrap(),
+        OutputFormat::Tsv
+    );
+    assert!(OutputFormat::from_str("xml", true).is_err());
+}
@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use bel7_cli::{
-    format_bold, format_dimmed, format_error, format_info, format_success, format_warning,
-    should_colorize, should_colorize_stderr,
+    ColorChoice, Verbosity, color_choice, format_bold, format_dimmed, format_error, format_info,
+    format_success, format_warning, set_color_choice, should_colorize, should_colorize_stderr,
 };
 
 #[test]
@@ -74,3 +74,133 @@ fn test_format_bold_returns_string() {
     let result = format_bold("test");
     assert!(result.contains("test"));
 }
+
+#[test]
+fn test_color_choice_default() {
+    assert_eq!(ColorChoice::default(), ColorChoice::Auto);
+}
+
+#[test]
+fn test_color_choice_always_and_never_ignore_terminal() {
+    assert!(ColorChoice::Always.resolve(false));
+    assert!(ColorChoice::Always.resolve(true));
+    assert!(!ColorChoice::Never.resolve(false));
+    assert!(!ColorChoice::Never.resolve(true));
+}
+
+// This is the only test that changes the process-wide color choice
+#[test]
+fn test_set_color_choice_affects_formatting() {
+    set_color_choice(ColorChoice::Always);
+    assert_eq!(color_choice(), ColorChoice::Always);
+    assert!(should_colorize());
+    assert!(should_colorize_stderr());
+    assert!(format_success("test").contains("\x1b["));
+
+    set_color_choice(ColorChoice::Never);
+    assert_eq!(color_choice(), ColorChoice::Never);
+    assert!(!should_colorize());
+    assert_eq!(format_error("test"), "test");
+    assert_eq!(format_bold("test"), "test");
+
+    set_color_choice(ColorChoice::Auto);
+    assert_eq!(color_choice(), ColorChoice::Auto);
+}
+
+#[test]
+fn test_verbosity_ordering() {
+    assert!(Verbosity::Quiet < Verbosity::Normal);
+    assert!(Verbosity::Normal < Verbosity::Verbose);
+    assert!(Verbosity::Verbose < Verbosity::Debug);
+    assert!(Verbosity::Debug < Verbosity::Trace);
+    assert_eq!(Verbosity::default(), Verbosity::Normal);
+}
+
+#[test]
+fn test_verbosity_from_counts() {
+    assert_eq!(Verbosity::from_counts(0, 0), Verbosity::Normal);
+    assert_eq!(Verbosity::from_counts(1, 0), Verbosity::Verbose);
+    assert_eq!(Verbosity::from_counts(3, 0), Verbosity::Trace);
+    assert_eq!(Verbosity::from_counts(0, 3), Verbosity::Quiet);
+    assert_eq!(Verbosity::from_counts(2, 1), Verbosity::Verbose);
+    assert_eq!(Verbosity::from_counts(u8::MAX, 0), Verbosity::Trace);
+}
+
+#[test]
+fn test_verbosity_allows() {
+    assert!(Verbosity::Debug.allows(Verbosity::Verbose));
+    assert!(!Verbosity::Normal.allows(Verbosity::Debug));
+    assert!(!Verbosity::Quiet.allows(Verbosity::Normal));
+}
+
+fn resolve_auto(is_terminal: bool, vars: &[(&str, &str)]) -> bool {
+    ColorChoice::Auto.resolve_with_env(is_terminal, |name| {
+        vars.iter()
+            .find(|(var, _)| *var == name)
+            .map(|(_, value)| value.to_string())
+    })
+}
+
+#[test]
+fn test_color_choice_auto_follows_terminal_without_overrides() {
+    assert!(resolve_auto(true, &[]));
+    assert!(!resolve_auto(false, &[]));
+    assert!(resolve_auto(
+        true,
+        &[("TERM", "xterm-256color"), ("CLICOLOR", "1")]
+    ));
+}
+
+#[test]
+fn test_color_choice_auto_no_color_wins() {
+    assert!(!resolve_auto(true, &[("NO_COLOR", "")]));
+    assert!(!resolve_auto(
+        true,
+        &[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]
+    ));
+    assert!(!resolve_auto(
+        true,
+        &[("NO_COLOR", "1"), ("FORCE_COLOR", "1")]
+    ));
+}
+
+#[test]
+fn test_color_choice_auto_force_overrides_terminal_and_term() {
+    assert!(resolve_auto(false, &[("CLICOLOR_FORCE", "1")]));
+    assert!(resolve_auto(false, &[("FORCE_COLOR", "true")]));
+    assert!(resolve_auto(
+        false,
+        &[("FORCE_COLOR", "1"), ("TERM", "dumb")]
+    ));
+    assert!(resolve_auto(
+        false,
+        &[("CLICOLOR_FORCE", "1"), ("CLICOLOR", "0")]
+    ));
+}
+
+#[test]
+fn test_color_choice_auto_disabled_force_values_are_ignored() {
+    for value in ["", "0", "false"] {
+        assert!(
+            !resolve_auto(false, &[("CLICOLOR_FORCE", value)]),
+            "{value:?}"
+        );
+        assert!(!resolve_auto(false, &[("FORCE_COLOR", value)]), "{value:?}");
+        assert!(resolve_auto(true, &[("FORCE_COLOR", value)]), "{value:?}");
+    }
+}
+
+#[test]
+fn test_color_choice_auto_dumb_terminal_and_clicolor() {
+    assert!(!resolve_auto(true, &[("TERM", "dumb")]));
+    assert!(!resolve_auto(true, &[("CLICOLOR", "0")]));
+    assert!(resolve_auto(true, &[("CLICOLOR", "1")]));
+}
+
+#[test]
+fn test_color_choice_always_and_never_ignore_environment() {
+    let env = |name: &str| (name == "NO_COLOR").then(|| "1".to_string());
+    assert!(ColorChoice::Always.resolve_with_env(false, env));
+    let env = |name: &str| (name == "FORCE_COLOR").then(|| "1".to_string());
+    assert!(!ColorChoice::Never.resolve_with_env(true, env));
+}
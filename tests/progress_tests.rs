@@ -18,7 +18,8 @@ use std::time::Duration;
 
 use bel7_cli::{
     BRAILLE_TICK_CHARS, DownloadReporter, InteractiveReporter, NonInteractiveReporter,
-    ProgressReporter, QuietReporter, SpinnerReporter, select_reporter,
+    ProgressReporter, QuietReporter, SpinnerReporter, Verbosity, select_reporter,
+    select_reporter_for_verbosity,
 };
 
 #[test]
@@ -80,6 +81,21 @@ fn test_select_reporter_quiet_takes_precedence() {
     let _ = reporter;
 }
 
+#[test]
+fn test_select_reporter_for_verbosity() {
+    for verbosity in [
+        Verbosity::Quiet,
+        Verbosity::Normal,
+        Verbosity::Verbose,
+        Verbosity::Debug,
+        Verbosity::Trace,
+    ] {
+        let mut reporter = select_reporter_for_verbosity(verbosity, true);
+        reporter.start(1, "Testing");
+        reporter.finish(1);
+    }
+}
+
 #[test]
 fn test_interactive_reporter_default() {
     let reporter = InteractiveReporter::default();
@@ -0,0 +1,205 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "tables")]
+
+use std::cmp::Ordering;
+
+use bel7_cli::{SortKey, TableError, natural_cmp, parse_sort_spec, sort_records};
+use tabled::Tabled;
+
+#[derive(Tabled, Debug, Clone, PartialEq)]
+struct QueueRow {
+    name: String,
+    messages: String,
+    #[tabled(rename = "Node")]
+    node: String,
+}
+
+fn row(name: &str, messages: &str, node: &str) -> QueueRow {
+    QueueRow {
+        name: name.into(),
+        messages: messages.into(),
+        node: node.into(),
+    }
+}
+
+fn names(rows: &[QueueRow]) -> Vec<&str> {
+    rows.iter().map(|r| r.name.as_str()).collect()
+}
+
+#[test]
+fn test_parse_sort_spec() {
+    let keys = parse_sort_spec("messages:desc, name ,,node:ASC").unwrap();
+    assert_eq!(
+        keys,
+        vec![
+            SortKey::descending("messages"),
+            SortKey::ascending("name"),
+            SortKey::ascending("node"),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_sort_spec_empty() {
+    assert!(parse_sort_spec("").unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_sort_spec_invalid_direction() {
+    let err = parse_sort_spec("name:up").unwrap_err();
+    assert_eq!(
+        err,
+        TableError::InvalidSortDirection {
+            key: "name".into(),
+            direction: "up".into(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid sort direction 'up' for 'name', expected 'asc' or 'desc'"
+    );
+}
+
+#[test]
+fn test_sort_records_natural_order() {
+    let mut rows = vec![
+        row("queue.10", "1", "a"),
+        row("queue.9", "1", "a"),
+        row("Queue.2", "1", "a"),
+    ];
+    sort_records(&mut rows, &[SortKey::ascending("name")]).unwrap();
+    assert_eq!(names(&rows), ["Queue.2", "queue.9", "queue.10"]);
+}
+
+#[test]
+fn test_sort_records_numeric_values() {
+    let mut rows = vec![
+        row("a", "1,024", "n"),
+        row("b", "-3.5", "n"),
+        row("c", "97.25", "n"),
+        row("d", "7", "n"),
+    ];
+    sort_records(&mut rows, &[SortKey::descending("messages")]).unwrap();
+    assert_eq!(names(&rows), ["a", "c", "d", "b"]);
+}
+
+#[test]
+fn test_sort_records_mixed_column_is_totally_ordered() {
+    // Compared pairwise, 3.12 < 3.9 numerically, but 3.9 < 3.9.1 < 3.12 naturally
+    let expected = ["3.9", "3.9.1", "3.12"];
+    for order in [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ] {
+        let mut rows: Vec<QueueRow> = order
+            .iter()
+            .map(|&idx| row(expected[idx], expected[idx], "n"))
+            .collect();
+        sort_records(&mut rows, &[SortKey::ascending("messages")]).unwrap();
+        assert_eq!(names(&rows), expected, "sorting {order:?}");
+    }
+}
+
+#[test]
+fn test_sort_records_two_part_versions() {
+    let mut rows: Vec<QueueRow> = ["3.9", "3.12", "3.10", "4.0"]
+        .into_iter()
+        .map(|version| row(version, version, "n"))
+        .collect();
+    sort_records(&mut rows, &[SortKey::ascending("messages")]).unwrap();
+    assert_eq!(names(&rows), ["3.9", "3.10", "3.12", "4.0"]);
+}
+
+#[test]
+fn test_sort_records_numeric_column_with_empty_values() {
+    let mut rows = vec![row("a", "10", "n"), row("b", "", "n"), row("c", "9", "n")];
+    sort_records(&mut rows, &[SortKey::ascending("messages")]).unwrap();
+    assert_eq!(names(&rows), ["b", "c", "a"]);
+}
+
+#[test]
+fn test_sort_records_multiple_keys() {
+    let mut rows = vec![
+        row("b", "5", "rabbit@2"),
+        row("a", "5", "rabbit@1"),
+        row("c", "200", "rabbit@1"),
+    ];
+    sort_records(&mut rows, &parse_sort_spec("messages:desc,name").unwrap()).unwrap();
+    assert_eq!(names(&rows), ["c", "a", "b"]);
+}
+
+#[test]
+fn test_sort_records_case_insensitive_headers() {
+    let mut rows = vec![row("a", "1", "rabbit@2"), row("b", "1", "rabbit@1")];
+    sort_records(&mut rows, &[SortKey::ascending("NODE")]).unwrap();
+    assert_eq!(names(&rows), ["b", "a"]);
+}
+
+#[test]
+fn test_sort_records_non_ascii_headers() {
+    #[derive(Tabled)]
+    struct LocalizedQueue {
+        name: &'static str,
+        #[tabled(rename = "État")]
+        state: &'static str,
+    }
+
+    let mut rows = vec![
+        LocalizedQueue {
+            name: "a",
+            state: "running",
+        },
+        LocalizedQueue {
+            name: "b",
+            state: "idle",
+        },
+    ];
+    sort_records(&mut rows, &parse_sort_spec("État").unwrap()).unwrap();
+    assert_eq!(rows[0].name, "b");
+}
+
+#[test]
+fn test_sort_records_is_stable() {
+    let mut rows = vec![row("b", "1", "x"), row("a", "1", "x"), row("c", "1", "x")];
+    sort_records(&mut rows, &[SortKey::ascending("messages")]).unwrap();
+    assert_eq!(names(&rows), ["b", "a", "c"]);
+}
+
+#[test]
+fn test_sort_records_unknown_key() {
+    let mut rows = vec![row("b", "1", "x"), row("a", "2", "x")];
+    let original = rows.clone();
+    let err = sort_records(&mut rows, &[SortKey::ascending("size")]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown sort key 'size', expected one of: name, messages, Node"
+    );
+    assert_eq!(rows, original);
+}
+
+#[test]
+fn test_natural_cmp() {
+    assert_eq!(natural_cmp("queue.9", "queue.10"), Ordering::Less);
+    assert_eq!(natural_cmp("a2b", "a10a"), Ordering::Less);
+    assert_eq!(natural_cmp("007", "7"), Ordering::Less);
+    assert_eq!(natural_cmp("abc", "abc"), Ordering::Equal);
+    assert_eq!(natural_cmp("abc", "abcd"), Ordering::Less);
+    assert_eq!(natural_cmp("B", "a"), Ordering::Greater);
+}
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use bel7_cli::{
-    DEFAULT_TERMINAL_WIDTH, Padding, StyledTable, TableStyle, build_table_with_columns,
-    display_option, display_option_or, parse_columns, responsive_width, terminal_width,
+    Aggregate, ColumnAlignment, ColumnOverflow, ColumnRef, DEFAULT_TERMINAL_WIDTH, FooterRow,
+    Padding, StyledTable, TableError, TableStyle, TextStyle, ThemeColor, build_table_with_columns,
+    build_table_with_columns_strict, display_option, display_option_or, display_width,
+    parse_columns, resolve_columns, responsive_width, strip_ansi, terminal_width,
 };
 use tabled::Tabled;
 
@@ -431,6 +433,93 @@ fn test_styled_table_wrap_column() {
     let _ = table.to_string();
 }
 
+#[derive(Tabled)]
+struct PolicyRow {
+    name: String,
+    pattern: String,
+    definition: String,
+}
+
+fn policy_rows() -> Vec<PolicyRow> {
+    vec![PolicyRow {
+        name: "limits".into(),
+        pattern: "^orders.eu-west".into(),
+        definition: "max-length: 1000 overflow: reject-publish".into(),
+    }]
+}
+
+#[test]
+fn test_styled_table_wrap_several_columns() {
+    let output = StyledTable::new()
+        .style(TableStyle::Psql)
+        .wrap_column(1, 8)
+        .wrap_column("Definition", 12)
+        .build(policy_rows())
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 6);
+    assert_eq!(lines[2], " limits | ^orders. | max-length:  ");
+    assert_eq!(lines[3], "        | eu-west  | 1000 overflo ");
+}
+
+#[test]
+fn test_styled_table_wrap_column_keep_words() {
+    let output = StyledTable::new()
+        .style(TableStyle::Psql)
+        .wrap_column_keep_words("definition", 20)
+        .build(policy_rows())
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[2].ends_with("| max-length: 1000  "));
+    assert!(lines[3].ends_with("| overflow:         "));
+    assert!(lines[4].ends_with("| reject-publish    "));
+}
+
+#[test]
+fn test_styled_table_wrap_hidden_column() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .wrap_column("definition", 12)
+        .wrap_column("pattern", 8)
+        .max_width(20)
+        .column_priority("name", 2)
+        .column_priority("pattern", 1)
+        .render(policy_rows());
+    assert!(output.contains(" limits | ^orders. \n"));
+    assert!(output.ends_with("\nHidden columns: definition"));
+}
+
+#[test]
+fn test_build_details_wrap_column_by_name() {
+    let rows = policy_rows();
+    let output = StyledTable::new()
+        .style(TableStyle::Psql)
+        .wrap_column_keep_words("definition", 20)
+        .build_details(&rows[0])
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[1], " pattern    | ^orders.eu-west   ");
+    assert_eq!(lines[2], " definition | max-length: 1000  ");
+    assert_eq!(lines[3], "            | overflow:         ");
+}
+
+#[test]
+fn test_expanded_wrap_column_by_name() {
+    let output = StyledTable::new()
+        .wrap_column("definition", 12)
+        .expanded()
+        .build(policy_rows())
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(lines[2], "pattern    | ^orders.eu-west");
+    assert_eq!(lines[3], "definition | max-length:    ");
+    assert_eq!(lines[4], "           | 1000 overflo   ");
+}
+
 #[test]
 fn test_styled_table_responsive_combo() {
     let data = vec![TestRow {
@@ -446,6 +535,1107 @@ fn test_styled_table_responsive_combo() {
     let _ = table.to_string();
 }
 
+#[derive(Tabled)]
+struct QueueDetails {
+    name: String,
+    #[tabled(rename = "Arguments")]
+    arguments: String,
+    messages: u64,
+}
+
+fn queue_details() -> QueueDetails {
+    QueueDetails {
+        name: "orders".into(),
+        arguments: "x-queue-type: quorum\nx-max-length: 1000".into(),
+        messages: 12,
+    }
+}
+
+#[test]
+fn test_build_details_lists_fields_as_rows() {
+    let table = StyledTable::new()
+        .style(TableStyle::Borderless)
+        .build_details(&queue_details());
+    let lines: Vec<String> = table
+        .to_string()
+        .lines()
+        .map(|l| l.trim_end().to_string())
+        .collect();
+    assert!(lines[0].starts_with(" name"));
+    assert!(lines[0].ends_with("orders"));
+    assert!(
+        lines
+            .iter()
+            .any(|l| l.contains("Arguments") && l.contains("quorum"))
+    );
+    assert!(lines.last().unwrap().ends_with("12"));
+}
+
+#[test]
+fn test_build_details_aligns_values() {
+    let table = StyledTable::new()
+        .style(TableStyle::Psql)
+        .replace_newlines(", ")
+        .build_details(&queue_details());
+    let output = table.to_string();
+    let separators: Vec<usize> = output.lines().map(|l| l.find('|').unwrap()).collect();
+    assert_eq!(separators.len(), 3);
+    assert!(separators.iter().all(|&pos| pos == separators[0]));
+    assert!(output.contains("x-queue-type: quorum, x-max-length: 1000"));
+}
+
+#[test]
+fn test_build_details_with_columns() {
+    let table = StyledTable::new()
+        .columns(parse_columns("messages,NAME"))
+        .build_details(&queue_details());
+    let output = table.to_string();
+    assert!(!output.contains("Arguments"));
+    let messages_pos = output.find("messages").unwrap();
+    let name_pos = output.find("name").unwrap();
+    assert!(messages_pos < name_pos);
+}
+
+#[test]
+fn test_build_details_truncates_values() {
+    let table = StyledTable::new()
+        .truncate_values(10)
+        .replace_newlines(" ")
+        .build_details(&queue_details());
+    let output = table.to_string();
+    assert!(output.contains("x-queue..."));
+    assert!(!output.contains("quorum"));
+}
+
+#[test]
+fn test_build_details_with_header_and_modern_style() {
+    let table = StyledTable::new()
+        .header("Queue")
+        .build_details(&queue_details());
+    let output = table.to_string();
+    assert!(output.contains("Queue"));
+    assert!(output.starts_with('╭'));
+    assert_eq!(output.matches('├').count(), 1);
+
+    let table = StyledTable::new().build_details(&queue_details());
+    assert_eq!(table.to_string().matches('├').count(), 0);
+}
+
+#[test]
+fn test_build_details_wraps_after_newline_replacement() {
+    let output = StyledTable::new()
+        .style(TableStyle::Psql)
+        .header("Queue")
+        .replace_newlines(" ")
+        .wrap_column_keep_words("arguments", 14)
+        .build_details(&queue_details())
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines[3], " Arguments | x-queue-type:  ");
+    assert_eq!(lines[4], "           | quorum         ");
+    assert_eq!(lines[5], "           | x-max-length:  ");
+}
+
+#[test]
+fn test_styled_table_columns() {
+    let data = vec![ThreeColumnRow {
+        id: 7,
+        name: "first".into(),
+        status: "active".into(),
+    }];
+
+    let table = StyledTable::new()
+        .style(TableStyle::Psql)
+        .columns(parse_columns("Status,id,unknown"))
+        .build(data);
+    let output = table.to_string();
+    assert!(output.contains("active"));
+    assert!(!output.contains("first"));
+    assert!(output.find("status").unwrap() < output.find("id").unwrap());
+}
+
+#[test]
+fn test_styled_table_truncate_values() {
+    let data = vec![TestRow {
+        name: "a rather long name".into(),
+        value: 1,
+    }];
+
+    let table = StyledTable::new().truncate_values(8).build(data);
+    let output = table.to_string();
+    assert!(output.contains("a rat..."));
+}
+
+#[test]
+fn test_styled_table_truncate_lines() {
+    let data = vec![TestRow {
+        name: "line 1\nline 2\nline 3\nline 4\nline 5".into(),
+        value: 1,
+    }];
+
+    let table = StyledTable::new().truncate_lines(2).build(data);
+    let output = table.to_string();
+    assert!(output.contains("line 2"));
+    assert!(!output.contains("line 3"));
+    assert!(output.contains("… (3 more lines)"));
+}
+
+#[test]
+fn test_styled_table_truncate_lines_short_values() {
+    let data = vec![TestRow {
+        name: "line 1\nline 2".into(),
+        value: 1,
+    }];
+
+    let output = StyledTable::new().truncate_lines(2).build(data).to_string();
+    assert!(output.contains("line 2"));
+    assert!(!output.contains("more line"));
+}
+
+#[test]
+fn test_styled_table_truncate_lines_and_values() {
+    let data = vec![TestRow {
+        name: "a rather long first line\nsecond\nthird".into(),
+        value: 1,
+    }];
+
+    let output = StyledTable::new()
+        .truncate_lines(1)
+        .truncate_values(12)
+        .build(data)
+        .to_string();
+    assert!(output.contains("a rather ..."));
+    assert!(!output.contains("second"));
+    assert!(output.contains("… (2 more..."));
+}
+
+#[derive(Tabled)]
+struct MessageRow {
+    id: String,
+    queue: String,
+}
+
+fn message_rows() -> Vec<MessageRow> {
+    ["3f2a9c10-7b1e", "3f2b0d44-91aa", "c01d55e2-0f3c"]
+        .into_iter()
+        .map(|id| MessageRow {
+            id: id.into(),
+            queue: "orders".into(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_styled_table_abbreviate_ids_by_name() {
+    let output = StyledTable::new()
+        .abbreviate_ids("ID", 4)
+        .build(message_rows())
+        .to_string();
+    assert!(output.contains("3f2a "));
+    assert!(output.contains("3f2b "));
+    assert!(output.contains("c01d "));
+    assert!(!output.contains("7b1e"));
+}
+
+#[test]
+fn test_styled_table_abbreviate_ids_by_index() {
+    let output = StyledTable::new()
+        .abbreviate_ids(0, 2)
+        .build(message_rows())
+        .to_string();
+    assert!(output.contains("3f2a "));
+    assert!(output.contains("c01d "));
+}
+
+#[test]
+fn test_styled_table_abbreviate_ids_unknown_column() {
+    let output = StyledTable::new()
+        .abbreviate_ids("message_id", 4)
+        .build(message_rows())
+        .to_string();
+    assert!(output.contains("3f2a9c10-7b1e"));
+}
+
+#[test]
+fn test_column_ref_resolve() {
+    let headers = vec!["name".to_string(), "value".to_string()];
+    assert_eq!(ColumnRef::from("Value").resolve(&headers), Some(1));
+    assert_eq!(ColumnRef::from(0).resolve(&headers), Some(0));
+    assert_eq!(ColumnRef::from(2).resolve(&headers), None);
+    assert_eq!(
+        ColumnRef::from("missing".to_string()).resolve(&headers),
+        None
+    );
+}
+
+#[derive(Tabled)]
+struct QueueStats {
+    name: String,
+    messages: String,
+    size: String,
+}
+
+fn queue_stats() -> Vec<QueueStats> {
+    vec![
+        QueueStats {
+            name: "orders".into(),
+            messages: "1,024".into(),
+            size: "12 MiB".into(),
+        },
+        QueueStats {
+            name: "events".into(),
+            messages: "7".into(),
+            size: "".into(),
+        },
+        QueueStats {
+            name: "audit.log.archive".into(),
+            messages: "-3.5".into(),
+            size: "97%".into(),
+        },
+    ]
+}
+
+fn line_containing(output: &str, needle: &str) -> String {
+    output
+        .lines()
+        .find(|line| line.contains(needle))
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_styled_table_align_numbers_right() {
+    let output = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .align_numbers_right()
+        .build(queue_stats())
+        .to_string();
+    assert!(line_containing(&output, "events").contains("|        7 |"));
+    assert!(line_containing(&output, "audit").contains("|    97% |"));
+    assert!(line_containing(&output, "events").starts_with("| events "));
+}
+
+#[test]
+fn test_styled_table_without_numeric_alignment() {
+    let output = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .build(queue_stats())
+        .to_string();
+    assert!(line_containing(&output, "events").contains("| 7        |"));
+}
+
+#[test]
+fn test_styled_table_align_column() {
+    let output = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .align_column("name", ColumnAlignment::Right)
+        .align_column(1, ColumnAlignment::Center)
+        .build(queue_stats())
+        .to_string();
+    assert!(line_containing(&output, "events").starts_with("|            events |"));
+    assert!(line_containing(&output, "events").contains("|    7     |"));
+}
+
+#[test]
+fn test_styled_table_align_column_overrides_numeric_alignment() {
+    let output = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .align_numbers_right()
+        .align_column("messages", ColumnAlignment::Left)
+        .build(queue_stats())
+        .to_string();
+    assert!(line_containing(&output, "events").contains("| 7        |"));
+}
+
+#[test]
+fn test_styled_table_align_numbers_right_skips_text_columns() {
+    let data = vec![
+        TestRow {
+            name: "12".into(),
+            value: 1,
+        },
+        TestRow {
+            name: "twelve".into(),
+            value: 100,
+        },
+    ];
+    let output = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .align_numbers_right()
+        .build(data)
+        .to_string();
+    assert!(line_containing(&output, "twelve").contains("|   100 |"));
+    assert!(line_containing(&output, "| 12").contains("| 12     |"));
+}
+
+#[derive(Tabled)]
+struct NodeRow {
+    node: String,
+    state: String,
+    messages: u64,
+}
+
+fn node_rows() -> Vec<NodeRow> {
+    vec![
+        NodeRow {
+            node: "rabbit@a".into(),
+            state: "running".into(),
+            messages: 20_000,
+        },
+        NodeRow {
+            node: "rabbit@b".into(),
+            state: "crashed".into(),
+            messages: 5,
+        },
+    ]
+}
+
+#[test]
+fn test_styled_table_cell_styles() {
+    let output = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .style_values(
+            "state",
+            [
+                ("crashed", TextStyle::new().color(ThemeColor::Red).bold()),
+                ("running", TextStyle::new().color(ThemeColor::Green)),
+            ],
+        )
+        .style_when(
+            "messages",
+            |value| value.parse::<u64>().is_ok_and(|n| n > 10_000),
+            TextStyle::new().color(ThemeColor::Yellow),
+        )
+        .colorize(true)
+        .build(node_rows())
+        .to_string();
+    let crashed = TextStyle::new()
+        .color(ThemeColor::Red)
+        .bold()
+        .paint("crashed");
+    let running = TextStyle::new().color(ThemeColor::Green).paint("running");
+    let busy = TextStyle::new().color(ThemeColor::Yellow).paint("20000");
+    assert!(output.contains(&crashed));
+    assert!(output.contains(&running));
+    assert!(output.contains(&busy));
+    assert!(line_containing(&output, "rabbit@b").contains("| 5        |"));
+}
+
+#[test]
+fn test_styled_table_cell_styles_keep_widths() {
+    let plain = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .style_values(
+            "state",
+            [
+                ("crashed", TextStyle::new().color(ThemeColor::Red).bold()),
+                ("running", TextStyle::new().color(ThemeColor::Green)),
+            ],
+        )
+        .style_when(
+            "messages",
+            |value| value.parse::<u64>().is_ok_and(|n| n > 10_000),
+            TextStyle::new().color(ThemeColor::Yellow),
+        )
+        .colorize(false)
+        .build(node_rows())
+        .to_string();
+    let colored = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .style_values(
+            "state",
+            [
+                ("crashed", TextStyle::new().color(ThemeColor::Red).bold()),
+                ("running", TextStyle::new().color(ThemeColor::Green)),
+            ],
+        )
+        .style_when(
+            "messages",
+            |value| value.parse::<u64>().is_ok_and(|n| n > 10_000),
+            TextStyle::new().color(ThemeColor::Yellow),
+        )
+        .colorize(true)
+        .build(node_rows())
+        .to_string();
+    assert_eq!(strip_ansi(&colored), plain);
+}
+
+#[test]
+fn test_styled_table_cell_styles_without_colors() {
+    let output = StyledTable::new()
+        .style(TableStyle::Ascii)
+        .style_values(
+            "state",
+            [
+                ("crashed", TextStyle::new().color(ThemeColor::Red).bold()),
+                ("running", TextStyle::new().color(ThemeColor::Green)),
+            ],
+        )
+        .style_when(
+            "messages",
+            |value| value.parse::<u64>().is_ok_and(|n| n > 10_000),
+            TextStyle::new().color(ThemeColor::Yellow),
+        )
+        .colorize(false)
+        .build(node_rows())
+        .to_string();
+    assert!(!output.contains('\x1b'));
+}
+
+#[test]
+fn test_styled_table_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<StyledTable>();
+}
+
+#[test]
+fn test_styled_table_cell_styles_first_rule_wins() {
+    let output = StyledTable::new()
+        .colorize(true)
+        .style_cells(0, |_| Some(TextStyle::new().underline()))
+        .style_cells("node", |_| Some(TextStyle::new().italic()))
+        .build(node_rows())
+        .to_string();
+    assert!(output.contains(&TextStyle::new().underline().paint("rabbit@a")));
+    assert!(!output.contains(&TextStyle::new().italic().paint("rabbit@a")));
+}
+
+#[derive(Tabled)]
+struct QueueSummary {
+    name: String,
+    messages: u64,
+    #[tabled(rename = "Consumers")]
+    consumers: u32,
+}
+
+fn queue_summaries() -> Vec<QueueSummary> {
+    vec![QueueSummary {
+        name: "orders".into(),
+        messages: 12,
+        consumers: 3,
+    }]
+}
+
+const QUEUE_ALIASES: &[(&str, &str)] = &[("msgs", "messages"), ("cons", "consumers")];
+
+#[test]
+fn test_resolve_columns() {
+    let columns = resolve_columns::<QueueSummary>(&parse_columns("CONSUMERS,name"), &[]).unwrap();
+    assert_eq!(columns, ["Consumers", "name"]);
+}
+
+#[test]
+fn test_column_names_match_non_ascii_headers() {
+    #[derive(Tabled)]
+    struct LocalizedQueue {
+        name: String,
+        #[tabled(rename = "État")]
+        state: String,
+    }
+
+    let columns = resolve_columns::<LocalizedQueue>(&parse_columns("État,NAME"), &[]).unwrap();
+    assert_eq!(columns, ["État", "name"]);
+
+    let headers = vec!["name".to_string(), "État".to_string()];
+    assert_eq!(ColumnRef::from("ÉTAT").resolve(&headers), Some(1));
+
+    let output = StyledTable::new()
+        .style(TableStyle::Psql)
+        .align_column("état", ColumnAlignment::Right)
+        .build(vec![LocalizedQueue {
+            name: "orders".into(),
+            state: "ok".into(),
+        }])
+        .to_string();
+    assert!(output.contains("| État \n"), "{output:?}");
+    assert!(output.contains("|   ok "), "{output:?}");
+}
+
+#[test]
+fn test_resolve_columns_aliases() {
+    let columns =
+        resolve_columns::<QueueSummary>(&parse_columns("msgs,cons"), QUEUE_ALIASES).unwrap();
+    assert_eq!(columns, ["messages", "Consumers"]);
+}
+
+#[test]
+fn test_resolve_columns_unknown_with_suggestions() {
+    let err = resolve_columns::<QueueSummary>(&parse_columns("nmae,mesages,size"), QUEUE_ALIASES)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TableError::UnknownColumns {
+            unknown: vec![
+                ("nmae".into(), Some("name".into())),
+                ("mesages".into(), Some("messages".into())),
+                ("size".into(), None),
+            ],
+            available: vec!["name".into(), "messages".into(), "Consumers".into()],
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "unknown columns 'nmae' (did you mean 'name'?), 'mesages' (did you mean 'messages'?), 'size', \
+         expected one of: name, messages, Consumers"
+    );
+}
+
+#[test]
+fn test_resolve_columns_suggests_aliases() {
+    let err = resolve_columns::<QueueSummary>(&parse_columns("msg"), QUEUE_ALIASES).unwrap_err();
+    assert!(err.to_string().contains("(did you mean 'msgs'?)"));
+}
+
+#[test]
+fn test_build_table_with_columns_strict() {
+    let table =
+        build_table_with_columns_strict(&queue_summaries(), &parse_columns("msgs"), QUEUE_ALIASES)
+            .unwrap();
+    let output = table.to_string();
+    assert!(output.contains("12"));
+    assert!(!output.contains("orders"));
+
+    let result = build_table_with_columns_strict(&queue_summaries(), &parse_columns("nmae"), &[]);
+    assert!(matches!(result, Err(TableError::UnknownColumns { .. })));
+}
+
+#[test]
+fn test_build_table_with_columns_canonical_headers() {
+    let table = build_table_with_columns(&queue_summaries(), &parse_columns("Consumers,NAME"));
+    let output = table.to_string();
+    let header = output.lines().nth(1).unwrap();
+    assert!(header.contains("Consumers"));
+    assert!(header.contains("name"));
+    assert!(header.find("Consumers").unwrap() < header.find("name").unwrap());
+}
+
+#[test]
+fn test_styled_table_rename_column() {
+    let output = StyledTable::new()
+        .rename_column("name", "Queue")
+        .rename_column(1, "Messages Ready")
+        .build(queue_summaries())
+        .to_string();
+    assert!(output.contains("Queue"));
+    assert!(output.contains("Messages Ready"));
+    assert!(!output.contains("name"));
+}
+
+#[test]
+fn test_styled_table_rename_column_with_column_selection() {
+    let output = StyledTable::new()
+        .style(TableStyle::Psql)
+        .header("Queues")
+        .columns(parse_columns("consumers,name"))
+        .rename_column("consumers", "Active Consumers")
+        .align_column("consumers", ColumnAlignment::Right)
+        .max_width(60)
+        .build(queue_summaries())
+        .to_string();
+    assert!(output.lines().next().unwrap().contains("Queues"));
+    assert!(output.contains("\n Active Consumers | name   \n"));
+    assert!(output.contains("\n                3 | orders "));
+}
+
+#[test]
+fn test_build_details_rename_column() {
+    let summary = &queue_summaries()[0];
+    let output = StyledTable::new()
+        .style(TableStyle::Psql)
+        .rename_column("messages", "Ready")
+        .build_details(summary)
+        .to_string();
+    assert!(output.contains(" Ready "));
+    assert!(!output.contains("messages"));
+}
+
+#[derive(Tabled)]
+struct WideQueueRow {
+    name: String,
+    vhost: String,
+    node: String,
+    messages: u64,
+}
+
+fn wide_queue_rows() -> Vec<WideQueueRow> {
+    vec![WideQueueRow {
+        name: "orders".into(),
+        vhost: "production-eu-west".into(),
+        node: "rabbit@node-1.eu-west.example".into(),
+        messages: 12,
+    }]
+}
+
+#[test]
+fn test_column_priority_hides_lowest_priority_first() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .max_width(45)
+        .column_priority("name", 3)
+        .column_priority("messages", 2)
+        .column_priority("vhost", 1)
+        .render(wide_queue_rows());
+    assert!(output.contains("production-eu-west"));
+    assert!(!output.contains("rabbit@node-1"));
+    assert!(output.ends_with("\nHidden columns: node"));
+    for line in output.lines() {
+        assert!(display_width(line) <= 45, "{line:?} is too wide");
+    }
+}
+
+#[test]
+fn test_column_priority_hides_several_columns() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .max_width(22)
+        .column_priority("name", 3)
+        .column_priority("messages", 2)
+        .column_priority("vhost", 1)
+        .render(wide_queue_rows());
+    assert!(output.contains("orders"));
+    assert!(output.contains("12"));
+    assert!(output.ends_with("\nHidden columns: vhost, node"));
+}
+
+#[test]
+fn test_column_priority_keeps_last_column_and_truncates() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .max_width(8)
+        .column_priority("name", 3)
+        .column_priority("messages", 2)
+        .column_priority("vhost", 1)
+        .render(wide_queue_rows());
+    assert!(output.ends_with("\nHidden columns: vhost, node, messages"));
+    for line in output.lines().filter(|line| !line.starts_with("Hidden")) {
+        assert!(display_width(line) <= 8, "{line:?} is too wide");
+    }
+}
+
+#[test]
+fn test_column_priority_no_hiding_when_table_fits() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .max_width(200)
+        .column_priority("name", 3)
+        .column_priority("messages", 2)
+        .column_priority("vhost", 1)
+        .render(wide_queue_rows());
+    assert!(output.contains("rabbit@node-1.eu-west.example"));
+    assert!(!output.contains("Hidden columns"));
+}
+
+#[test]
+fn test_column_priority_uses_display_names_and_keeps_alignment() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .max_width(45)
+        .column_priority("name", 3)
+        .column_priority("messages", 2)
+        .column_priority("vhost", 1)
+        .style(TableStyle::Psql)
+        .rename_column("node", "Node Name")
+        .align_column("messages", ColumnAlignment::Right)
+        .render(wide_queue_rows());
+    assert!(output.ends_with("\nHidden columns: Node Name"));
+    assert!(output.contains(" messages \n"));
+    assert!(output.contains("|       12 \n") || output.contains("|       12\n"));
+}
+
+#[test]
+fn test_max_width_without_priorities_truncates() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .max_width(40)
+        .render(wide_queue_rows());
+    assert!(!output.contains("Hidden columns"));
+    for line in output.lines() {
+        assert!(display_width(line) <= 40, "{line:?} is too wide");
+    }
+}
+
+#[test]
+fn test_max_width_shrinks_columns_proportionally() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .max_width(50)
+        .build(wide_queue_rows())
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines[0],
+        " name | vhost       | node             | messages "
+    );
+    assert_eq!(
+        lines[2],
+        " o... | producti... | rabbit@node-1... | 12       "
+    );
+}
+
+#[test]
+fn test_max_width_shrinks_fixed_columns_last() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .max_width(50)
+        .fixed_column("node")
+        .build(wide_queue_rows())
+        .to_string();
+    assert!(output.contains(" o... | pr... | rabbit@node-1.eu-we... | 12       "));
+    for line in output.lines() {
+        assert!(display_width(line) <= 50, "{line:?} is too wide");
+    }
+}
+
+#[test]
+fn test_column_min_width() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .max_width(50)
+        .column_min_width("vhost", 18)
+        .build(wide_queue_rows())
+        .to_string();
+    assert!(output.contains(" o... | production-eu-west | rabbit... | 12       "));
+}
+
+#[test]
+fn test_column_max_width_without_table_max_width() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .column_max_width("node", 12)
+        .build(wide_queue_rows())
+        .to_string();
+    assert!(output.contains(" orders | production-eu-west | rabbit@no... | 12       "));
+}
+
+#[test]
+fn test_column_overflow_truncate_middle() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .column_max_width("node", 12)
+        .column_overflow("node", ColumnOverflow::TruncateMiddle)
+        .build(wide_queue_rows())
+        .to_string();
+    assert!(output.contains("| rabbi...mple |"));
+}
+
+#[test]
+fn test_column_overflow_wrap() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .column_max_width(2, 14)
+        .column_overflow(2, ColumnOverflow::Wrap)
+        .build(wide_queue_rows())
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[2].contains("| rabbit@node-1. |"));
+    assert!(lines[3].contains("| eu-west.exampl |"));
+}
+
+#[test]
+fn test_column_overflow_wrap_styled_cells() {
+    let output = StyledTable::new()
+        .colorize(true)
+        .style(TableStyle::Ascii)
+        .style_values(
+            "state",
+            [
+                ("crashed", TextStyle::new().color(ThemeColor::Red).bold()),
+                ("running", TextStyle::new().color(ThemeColor::Green)),
+            ],
+        )
+        .column_max_width("state", 3)
+        .column_overflow("state", ColumnOverflow::Wrap)
+        .build(node_rows())
+        .to_string();
+    let green = |text| TextStyle::new().color(ThemeColor::Green).paint(text);
+    for chunk in ["run", "nin", "g"] {
+        assert!(output.contains(&green(chunk)), "{output:?}");
+    }
+    let plain = strip_ansi(&output);
+    assert!(!plain.contains("1m") && !plain.contains("2m"), "{plain:?}");
+}
+
+#[test]
+fn test_column_overflow_wrap_with_impossible_budget() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .max_width(20)
+        .column_overflow("state", ColumnOverflow::Wrap)
+        .build(node_rows())
+        .to_string();
+    for line in output.lines() {
+        assert!(display_width(line) <= 20, "{line:?} is too wide");
+    }
+    let state: Vec<&str> = output
+        .lines()
+        .skip(3)
+        .map(|line| line.split('|').nth(1).unwrap_or_default().trim())
+        .collect();
+    assert_eq!(state[..2], ["runn", "ing"]);
+}
+
+#[test]
+fn test_column_widths_fit_when_table_is_narrow_enough() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .max_width(200)
+        .fixed_column("name")
+        .build(wide_queue_rows())
+        .to_string();
+    assert!(output.contains("rabbit@node-1.eu-west.example"));
+}
+
+#[derive(Tabled)]
+struct FooterQueueRow {
+    name: &'static str,
+    vhost: &'static str,
+    messages: &'static str,
+}
+
+fn footer_queue_rows() -> Vec<FooterQueueRow> {
+    vec![
+        FooterQueueRow {
+            name: "orders",
+            vhost: "/",
+            messages: "1,200",
+        },
+        FooterQueueRow {
+            name: "events",
+            vhost: "/",
+            messages: "30",
+        },
+        FooterQueueRow {
+            name: "audit",
+            vhost: "prod",
+            messages: "",
+        },
+    ]
+}
+
+#[test]
+fn test_styled_table_footer_rows() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .align_numbers_right()
+        .footer(
+            FooterRow::new()
+                .text(0, "Total")
+                .aggregate("messages", Aggregate::Sum),
+        )
+        .footer(
+            FooterRow::new()
+                .text(0, "Average")
+                .aggregate("vhost", Aggregate::Count)
+                .aggregate("messages", Aggregate::Avg),
+        )
+        .build(footer_queue_rows())
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines[5], "---------+-------+----------");
+    assert_eq!(lines[6], " Total   |       |     1230 ");
+    assert_eq!(lines[7], " Average | 3     |      615 ");
+}
+
+#[test]
+fn test_styled_table_footer_with_header_panel() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .align_numbers_right()
+        .footer(
+            FooterRow::new()
+                .text(0, "Total")
+                .aggregate("messages", Aggregate::Sum),
+        )
+        .footer(
+            FooterRow::new()
+                .text(0, "Average")
+                .aggregate("vhost", Aggregate::Count)
+                .aggregate("messages", Aggregate::Avg),
+        )
+        .header("Queues")
+        .build(footer_queue_rows())
+        .to_string();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0].trim(), "Queues");
+    assert_eq!(lines[6], "---------+-------+----------");
+    assert_eq!(lines[7], " Total   |       |     1230 ");
+}
+
+#[test]
+fn test_styled_table_footer_omitted_without_header_row() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .align_numbers_right()
+        .footer(
+            FooterRow::new()
+                .text(0, "Total")
+                .aggregate("messages", Aggregate::Sum),
+        )
+        .footer(
+            FooterRow::new()
+                .text(0, "Average")
+                .aggregate("vhost", Aggregate::Count)
+                .aggregate("messages", Aggregate::Avg),
+        )
+        .remove_header_row()
+        .build(footer_queue_rows())
+        .to_string();
+    assert!(output.contains("orders"));
+    assert!(!output.contains("Total"));
+    assert!(!output.contains("Average"));
+}
+
+#[test]
+fn test_styled_table_footer_follows_hidden_columns() {
+    let output = StyledTable::new()
+        .colorize(false)
+        .style(TableStyle::Psql)
+        .align_numbers_right()
+        .footer(
+            FooterRow::new()
+                .text(0, "Total")
+                .aggregate("messages", Aggregate::Sum),
+        )
+        .footer(
+            FooterRow::new()
+                .text(0, "Average")
+                .aggregate("vhost", Aggregate::Count)
+                .aggregate("messages", Aggregate::Avg),
+        )
+        .max_width(20)
+        .column_priority("name", 2)
+        .column_priority("messages", 1)
+        .render(footer_queue_rows());
+    assert!(output.contains(" Total   |     1230 \n"));
+    assert!(output.ends_with("Hidden columns: vhost"));
+}
+
+#[test]
+fn test_aggregate_compute() {
+    let values = ["1,200", "30", "", "n/a", "-5.5"];
+    assert_eq!(Aggregate::Sum.compute(values), "1224.5");
+    assert_eq!(Aggregate::Min.compute(values), "-5.5");
+    assert_eq!(Aggregate::Max.compute(values), "1200");
+    assert_eq!(Aggregate::Avg.compute(values), "408.17");
+    assert_eq!(Aggregate::Count.compute(values), "4");
+
+    assert_eq!(Aggregate::Sum.compute(["n/a"]), "0");
+    assert_eq!(Aggregate::Max.compute(["n/a"]), "");
+    assert_eq!(Aggregate::Avg.compute([]), "");
+}
+
+fn expanded_lines(table: tabled::Table) -> Vec<String> {
+    table
+        .to_string()
+        .lines()
+        .map(|l| l.trim_end().to_string())
+        .collect()
+}
+
+#[test]
+fn test_styled_table_expanded() {
+    let data = vec![
+        TestRow {
+            name: "orders.eu".into(),
+            value: 1,
+        },
+        TestRow {
+            name: "events".into(),
+            value: 22,
+        },
+    ];
+
+    let lines = expanded_lines(StyledTable::new().expanded().build(data));
+    assert_eq!(
+        lines,
+        vec![
+            "-[ RECORD 1 ]----",
+            "name  | orders.eu",
+            "value | 1",
+            "-[ RECORD 2 ]----",
+            "name  | events",
+            "value | 22",
+        ]
+    );
+}
+
+#[test]
+fn test_styled_table_expanded_separator_spans_table_width() {
+    let data = vec![TestRow {
+        name: "a much longer name".into(),
+        value: 1,
+    }];
+
+    let output = StyledTable::new().expanded().build(data).to_string();
+    let widths: Vec<usize> = output.lines().map(|l| l.chars().count()).collect();
+    assert!(output.starts_with("-[ RECORD 1 ]---"));
+    assert!(widths.iter().all(|&w| w == widths[0]));
+}
+
+#[test]
+fn test_styled_table_expanded_with_header_and_columns() {
+    let data = vec![ThreeColumnRow {
+        id: 1,
+        name: "first".into(),
+        status: "active".into(),
+    }];
+
+    let lines = expanded_lines(
+        StyledTable::new()
+            .header("Queues")
+            .columns(parse_columns("status,name"))
+            .expanded()
+            .build(data),
+    );
+    assert_eq!(lines[0], "Queues");
+    assert!(lines[1].starts_with("-[ RECORD 1 ]-"));
+    assert_eq!(lines[2], "status | active");
+    assert_eq!(lines[3], "name   | first");
+    assert_eq!(lines.len(), 4);
+}
+
+#[test]
+fn test_styled_table_expanded_empty_data() {
+    let data: Vec<TestRow> = vec![];
+    let output = StyledTable::new().expanded().build(data).to_string();
+    assert!(!output.contains("RECORD"));
+}
+
+#[test]
+fn test_styled_table_expanded_when_wider_than() {
+    let data = vec![TestRow {
+        name: "test".into(),
+        value: 1,
+    }];
+
+    let narrow = StyledTable::new()
+        .expanded_when_wider_than(0.01)
+        .build(data.clone())
+        .to_string();
+    assert!(narrow.contains("-[ RECORD 1 ]-"));
+
+    let wide = StyledTable::new()
+        .expanded_when_wider_than(100.0)
+        .build(data)
+        .to_string();
+    assert!(!wide.contains("RECORD"));
+    assert!(wide.contains("name"));
+}
+
 mod proptests {
     use super::*;
     use proptest::prelude::*;
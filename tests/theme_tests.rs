@@ -0,0 +1,111 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_cli::{ColorChoice, Console, LevelStyle, TextStyle, Theme, ThemeColor, ThemeName};
+
+fn render_all(theme: Theme, choice: ColorChoice) -> (String, String) {
+    let mut console = Console::new(Vec::new(), Vec::new())
+        .with_color_choice(choice)
+        .with_theme(theme);
+    console.success("a").unwrap();
+    console.warning("b").unwrap();
+    console.info("c").unwrap();
+    console.dimmed("d").unwrap();
+    console.error("e").unwrap();
+    let (out, err) = console.into_parts();
+    (
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    )
+}
+
+#[test]
+fn test_default_theme_is_unicode() {
+    assert_eq!(Theme::default(), Theme::unicode());
+    assert_eq!(Theme::from(ThemeName::default()), Theme::unicode());
+}
+
+#[test]
+fn test_unicode_theme_symbols() {
+    let (out, err) = render_all(Theme::unicode(), ColorChoice::Never);
+    assert_eq!(out, "✓ a\n! b\n→ c\nd\n");
+    assert_eq!(err, "✗ e\n");
+}
+
+#[test]
+fn test_ascii_theme_symbols() {
+    let (out, err) = render_all(Theme::ascii(), ColorChoice::Never);
+    assert_eq!(out, "+ a\n! b\n> c\nd\n");
+    assert_eq!(err, "x e\n");
+    assert!(out.is_ascii());
+}
+
+#[test]
+fn test_plain_theme_has_no_symbols_or_colors() {
+    let (out, err) = render_all(Theme::plain(), ColorChoice::Always);
+    assert_eq!(out, "a\nb\nc\nd\n");
+    assert_eq!(err, "e\n");
+}
+
+#[test]
+fn test_unicode_theme_colors_symbols() {
+    let (out, _) = render_all(Theme::unicode(), ColorChoice::Always);
+    assert!(out.contains("\x1b["));
+    assert!(out.contains("✓"));
+}
+
+#[test]
+fn test_custom_level_style() {
+    let mut theme = Theme::ascii();
+    theme.info = LevelStyle::new("[i]", TextStyle::new());
+    let (out, _) = render_all(theme, ColorChoice::Always);
+    assert!(out.contains("[i] c\n"));
+}
+
+#[test]
+fn test_text_style_paint() {
+    let plain = TextStyle::new();
+    assert!(plain.is_plain());
+    assert_eq!(plain.paint("x"), "x");
+
+    let styled = TextStyle::new().color(ThemeColor::Cyan).bold().underline();
+    assert!(!styled.is_plain());
+    let painted = styled.paint("x");
+    assert!(painted.contains("\x1b["));
+    assert!(painted.contains('x'));
+}
+
+#[test]
+fn test_theme_name_conversion() {
+    assert_eq!(Theme::from(ThemeName::Ascii), Theme::ascii());
+    assert_eq!(Theme::from(ThemeName::Plain), Theme::plain());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_serde_round_trip() {
+    let json = serde_json::to_string(&Theme::ascii()).unwrap();
+    let theme: Theme = serde_json::from_str(&json).unwrap();
+    assert_eq!(theme, Theme::ascii());
+
+    let name: ThemeName = serde_json::from_str("\"Plain\"").unwrap();
+    assert_eq!(name, ThemeName::Plain);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_text_style_deserializes_with_defaults() {
+    let style: TextStyle = serde_json::from_str(r#"{"color": "Magenta", "bold": true}"#).unwrap();
+    assert_eq!(style, TextStyle::new().color(ThemeColor::Magenta).bold());
+}
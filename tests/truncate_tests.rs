@@ -12,7 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use bel7_cli::{truncate_middle, truncate_string, truncate_with_suffix};
+use bel7_cli::{
+    PathCollapse, Truncated, TruncationStrategy, abbreviate_ids, ansi_display_width, display_width,
+    more_lines_marker, strip_ansi, truncate_ansi, truncate_ansi_with_suffix, truncate_at_word,
+    truncate_at_word_with_suffix, truncate_lines, truncate_middle, truncate_middle_to_width,
+    truncate_path_with_home, truncate_string, truncate_to_width, truncate_to_width_with_suffix,
+    truncate_with_suffix,
+};
 
 #[test]
 fn test_truncate_short_string() {
@@ -79,3 +85,437 @@ fn test_truncate_middle_preserves_both_ends() {
 fn test_truncate_with_unicode_suffix() {
     assert_eq!(truncate_with_suffix("Hello, World!", 6, "…"), "Hello…");
 }
+
+#[test]
+fn test_display_width() {
+    assert_eq!(display_width(""), 0);
+    assert_eq!(display_width("abc"), 3);
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("🇯🇵"), 2);
+    assert_eq!(display_width("cafe\u{301}"), 4);
+}
+
+#[test]
+fn test_truncate_to_width_ascii_matches_truncate_string() {
+    assert_eq!(truncate_to_width("Hello, World!", 8), "Hello...");
+    assert_eq!(truncate_to_width("Hello", 5), "Hello");
+}
+
+#[test]
+fn test_truncate_to_width_cjk() {
+    let result = truncate_to_width("日本語のキュー", 8);
+    assert_eq!(result, "日本...");
+    assert!(display_width(&result) <= 8);
+}
+
+#[test]
+fn test_truncate_to_width_does_not_exceed_budget_with_wide_chars() {
+    // "日" takes two columns, so it only fits when two columns are left before the suffix
+    assert_eq!(truncate_to_width("日本語", 4), "...");
+    assert_eq!(truncate_to_width("日本語", 5), "日...");
+}
+
+#[test]
+fn test_truncate_to_width_keeps_combining_marks() {
+    let s = "re\u{301}sume\u{301} of queues";
+    let result = truncate_to_width_with_suffix(s, 7, "…");
+    assert_eq!(result, "re\u{301}sume\u{301}…");
+}
+
+#[test]
+fn test_truncate_to_width_keeps_zwj_sequences() {
+    let family = "👨\u{200d}👩\u{200d}👧";
+    let s = format!("{family}{family}{family}");
+    let result = truncate_to_width_with_suffix(&s, 5, "…");
+    assert_eq!(result, format!("{family}{family}…"));
+}
+
+#[test]
+fn test_truncate_to_width_keeps_flags() {
+    let result = truncate_to_width_with_suffix("🇯🇵🇫🇷🇩🇪", 5, "…");
+    assert_eq!(result, "🇯🇵🇫🇷…");
+}
+
+#[test]
+fn test_truncate_to_width_smaller_than_suffix() {
+    assert_eq!(truncate_to_width("Hello, World!", 2), "..");
+    assert_eq!(truncate_to_width("Hello, World!", 0), "");
+}
+
+#[test]
+fn test_truncate_middle_to_width() {
+    assert_eq!(truncate_middle_to_width("abcdefghij", 7), "ab...ij");
+    assert_eq!(truncate_middle_to_width("short", 10), "short");
+
+    let result = truncate_middle_to_width("日本語/キュー/注文", 11);
+    assert!(display_width(&result) <= 11);
+    assert!(result.starts_with("日本"));
+    assert!(result.ends_with("注文"));
+}
+
+#[test]
+fn test_strip_ansi() {
+    assert_eq!(strip_ansi("\x1b[1;32m✓\x1b[0m done"), "✓ done");
+    assert_eq!(
+        strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"),
+        "link"
+    );
+    assert_eq!(strip_ansi("plain"), "plain");
+}
+
+#[test]
+fn test_ansi_display_width() {
+    assert_eq!(ansi_display_width("\x1b[31m日本\x1b[39m"), 4);
+    assert_eq!(
+        ansi_display_width("\x1b]8;;https://example.com\x07link\x1b]8;;\x07"),
+        4
+    );
+}
+
+#[test]
+fn test_truncate_ansi_fits() {
+    let colored = "\x1b[32mok\x1b[39m";
+    assert_eq!(truncate_ansi(colored, 2), colored);
+}
+
+#[test]
+fn test_truncate_ansi_closes_open_style() {
+    assert_eq!(
+        truncate_ansi("\x1b[31mconnection refused\x1b[39m", 10),
+        "\x1b[31mconnect...\x1b[0m"
+    );
+}
+
+#[test]
+fn test_truncate_ansi_after_reset() {
+    assert_eq!(
+        truncate_ansi("\x1b[31mbad\x1b[0m and more text", 10),
+        "\x1b[31mbad\x1b[0m and..."
+    );
+}
+
+#[test]
+fn test_truncate_ansi_never_cuts_an_escape() {
+    let result = truncate_ansi_with_suffix("abc\x1b[38;5;208mdefgh\x1b[0m", 4, "…");
+    assert_eq!(result, "abc\x1b[38;5;208m…\x1b[0m");
+    let result = truncate_ansi_with_suffix("abcdef\x1b[38;5;208mgh\x1b[0m", 4, "…");
+    assert_eq!(result, "abc…");
+}
+
+#[test]
+fn test_truncate_ansi_closes_hyperlink() {
+    let link = "\x1b]8;;https://example.com/queues\x1b\\all queues\x1b]8;;\x1b\\";
+    assert_eq!(
+        truncate_ansi_with_suffix(link, 4, "…"),
+        "\x1b]8;;https://example.com/queues\x1b\\all…\x1b]8;;\x1b\\"
+    );
+}
+
+#[test]
+fn test_truncate_ansi_wide_characters() {
+    let result = truncate_ansi_with_suffix("\x1b[1m日本語テキスト\x1b[22m", 7, "…");
+    assert_eq!(result, "\x1b[1m日本語…\x1b[0m");
+}
+
+#[test]
+fn test_truncate_at_word() {
+    assert_eq!(truncate_at_word("The quick brown fox", 14), "The quick...");
+    assert_eq!(
+        truncate_at_word("The quick brown fox", 19),
+        "The quick brown fox"
+    );
+}
+
+#[test]
+fn test_truncate_at_word_exact_boundary() {
+    assert_eq!(
+        truncate_at_word_with_suffix("one two three", 8, "…"),
+        "one two…"
+    );
+}
+
+#[test]
+fn test_truncate_at_word_single_long_word() {
+    assert_eq!(truncate_at_word("Supercalifragilistic", 10), "Superca...");
+}
+
+#[test]
+fn test_truncate_at_word_wide_characters() {
+    assert_eq!(
+        truncate_at_word_with_suffix("日本 語のテキスト", 9, "…"),
+        "日本…"
+    );
+}
+
+const SEGMENT_PATH: &str = "/var/lib/rabbitmq/mnesia/rabbit@host/queues/ABC123/segment.idx";
+
+#[test]
+fn test_truncate_path_fits() {
+    let result = truncate_path_with_home(SEGMENT_PATH, 80, PathCollapse::Ellipsis, None);
+    assert_eq!(result, SEGMENT_PATH);
+}
+
+#[test]
+fn test_truncate_path_ellipsis() {
+    let result = truncate_path_with_home(SEGMENT_PATH, 35, PathCollapse::Ellipsis, None);
+    assert_eq!(result, "/var/…/queues/ABC123/segment.idx");
+
+    let result = truncate_path_with_home(SEGMENT_PATH, 20, PathCollapse::Ellipsis, None);
+    assert_eq!(result, "/var/…/segment.idx");
+}
+
+#[test]
+fn test_truncate_path_abbreviate() {
+    let result = truncate_path_with_home(SEGMENT_PATH, 45, PathCollapse::Abbreviate, None);
+    assert_eq!(result, "/v/l/r/m/r/queues/ABC123/segment.idx");
+}
+
+#[test]
+fn test_truncate_path_abbreviate_then_ellipsis() {
+    let result = truncate_path_with_home(SEGMENT_PATH, 25, PathCollapse::Abbreviate, None);
+    assert_eq!(result, "/v/…/m/r/q/A/segment.idx");
+}
+
+#[test]
+fn test_truncate_path_abbreviate_keeps_dot() {
+    let result = truncate_path_with_home(
+        "/home/user/.config/app/settings.toml",
+        30,
+        PathCollapse::Abbreviate,
+        Some("/home/user"),
+    );
+    assert_eq!(result, "~/.config/app/settings.toml");
+
+    let result = truncate_path_with_home(
+        "/home/user/.config/application/settings.toml",
+        22,
+        PathCollapse::Abbreviate,
+        Some("/home/user"),
+    );
+    assert_eq!(result, "~/.c/a/settings.toml");
+}
+
+#[test]
+fn test_truncate_path_home() {
+    let result = truncate_path_with_home(
+        "/home/user/projects/bel7-cli/src/tables.rs",
+        20,
+        PathCollapse::Ellipsis,
+        Some("/home/user/"),
+    );
+    assert_eq!(result, "~/…/src/tables.rs");
+
+    let result = truncate_path_with_home(
+        "/home/username/a",
+        80,
+        PathCollapse::Ellipsis,
+        Some("/home/user"),
+    );
+    assert_eq!(result, "/home/username/a");
+}
+
+#[test]
+fn test_truncate_path_relative() {
+    let result = truncate_path_with_home(
+        "target/debug/build/deps/libbel7_cli.rlib",
+        28,
+        PathCollapse::Ellipsis,
+        None,
+    );
+    assert_eq!(result, "target/…/libbel7_cli.rlib");
+}
+
+#[test]
+fn test_truncate_path_long_file_name() {
+    let result = truncate_path_with_home(
+        "/tmp/a-very-long-file-name-that-does-not-fit.log",
+        20,
+        PathCollapse::Ellipsis,
+        None,
+    );
+    assert_eq!(display_width(&result), 20);
+    assert!(result.starts_with("/tmp/a-v"));
+    assert!(result.ends_with("fit.log"));
+}
+
+#[test]
+fn test_truncate_path_only_file_name_fits() {
+    let result = truncate_path_with_home("/var/lib/segment.idx", 12, PathCollapse::Ellipsis, None);
+    assert_eq!(result, "segment.idx");
+
+    let result = truncate_path_with_home("/var/lib/segment.idx", 13, PathCollapse::Ellipsis, None);
+    assert_eq!(result, "…/segment.idx");
+}
+
+#[test]
+fn test_truncate_path_keeps_root_when_first_directory_does_not_fit() {
+    let path = "/a/b/c/d/e/f/file.txt";
+    let result = truncate_path_with_home(path, 12, PathCollapse::Abbreviate, None);
+    assert_eq!(result, "/…/file.txt");
+
+    let result = truncate_path_with_home(path, 10, PathCollapse::Abbreviate, None);
+    assert_eq!(result, "…/file.txt");
+
+    let result = truncate_path_with_home(path, 13, PathCollapse::Ellipsis, None);
+    assert_eq!(result, "/a/…/file.txt");
+}
+
+#[test]
+fn test_truncated_without_limit() {
+    assert_eq!(
+        Truncated::new("a rather long name").to_string(),
+        "a rather long name"
+    );
+}
+
+#[test]
+fn test_truncated_precision() {
+    assert_eq!(format!("{:.20}", Truncated::new("short")), "short");
+    assert_eq!(
+        format!("{:.8}", Truncated::new("a rather long name")),
+        "a rat..."
+    );
+}
+
+#[test]
+fn test_truncated_max_width_and_precision() {
+    let value = Truncated::new("a rather long name").max_width(10);
+    assert_eq!(format!("{}", value), "a rathe...");
+    assert_eq!(format!("{:.6}", value), "a r...");
+    assert_eq!(format!("{:.30}", value), "a rathe...");
+}
+
+#[test]
+fn test_truncated_strategies() {
+    let value = Truncated::new("abcdefghij").max_width(7);
+    assert_eq!(value.to_string(), "abcd...");
+    assert_eq!(value.middle().to_string(), "ab...ij");
+    assert_eq!(value.start().to_string(), "...ghij");
+    assert_eq!(
+        value
+            .strategy(TruncationStrategy::End)
+            .suffix("…")
+            .to_string(),
+        "abcdef…"
+    );
+}
+
+#[test]
+fn test_truncated_padding() {
+    let value = Truncated::new("queue");
+    assert_eq!(format!("[{:8}]", value), "[queue   ]");
+    assert_eq!(format!("[{:>8}]", value), "[   queue]");
+    assert_eq!(format!("[{:*^9}]", value), "[**queue**]");
+    assert_eq!(
+        format!("[{:<6.4}]", Truncated::new("queues").suffix("…")),
+        "[que…  ]"
+    );
+}
+
+#[test]
+fn test_truncated_wide_characters() {
+    assert_eq!(format!("{:.7}", Truncated::new("キューの名前")), "キュ...");
+    assert_eq!(format!("[{:>8}]", Truncated::new("キュー")), "[  キュー]");
+}
+
+#[test]
+fn test_truncated_smaller_than_suffix() {
+    assert_eq!(format!("{:.2}", Truncated::new("Hello, World!")), "..");
+}
+
+#[test]
+fn test_truncate_lines() {
+    let text = "one\ntwo\nthree\nfour";
+    assert_eq!(truncate_lines(text, 4), text);
+    assert_eq!(truncate_lines(text, 10), text);
+    assert_eq!(truncate_lines(text, 3), "one\ntwo\nthree\n… (1 more line)");
+    assert_eq!(truncate_lines(text, 1), "one\n… (3 more lines)");
+    assert_eq!(truncate_lines(text, 0), "… (4 more lines)");
+}
+
+#[test]
+fn test_truncate_lines_crlf() {
+    assert_eq!(
+        truncate_lines("one\r\ntwo\r\nthree", 1),
+        "one\n… (2 more lines)"
+    );
+}
+
+#[test]
+fn test_truncate_lines_trailing_newline() {
+    assert_eq!(truncate_lines("one\ntwo\n", 2), "one\ntwo\n");
+}
+
+#[test]
+fn test_more_lines_marker() {
+    assert_eq!(more_lines_marker(1), "… (1 more line)");
+    assert_eq!(more_lines_marker(37), "… (37 more lines)");
+}
+
+#[test]
+fn test_abbreviate_ids_minimum_length() {
+    assert_eq!(abbreviate_ids(&["abcdef", "bcdefg"], 3), ["abc", "bcd"]);
+}
+
+#[test]
+fn test_abbreviate_ids_extends_until_unique() {
+    let ids = vec![
+        "550e8400-e29b".to_string(),
+        "550e8411-f00d".to_string(),
+        "6ba7b810-9dad".to_string(),
+    ];
+    assert_eq!(abbreviate_ids(&ids, 4), ["550e840", "550e841", "6ba7b81"]);
+}
+
+#[test]
+fn test_abbreviate_ids_duplicates_and_prefixes() {
+    assert_eq!(
+        abbreviate_ids(&["abc", "abcd", "abc"], 1),
+        ["abc", "abcd", "abc"]
+    );
+}
+
+#[test]
+fn test_abbreviate_ids_empty() {
+    let ids: [&str; 0] = [];
+    assert!(abbreviate_ids(&ids, 7).is_empty());
+}
+
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn abbreviated_ids_stay_unique(ids in proptest::collection::hash_set("[0-9a-f]{1,12}", 0..20), min_len in 1usize..8) {
+            let ids: Vec<String> = ids.into_iter().collect();
+            let abbreviated = abbreviate_ids(&ids, min_len);
+            let unique: std::collections::HashSet<&String> = abbreviated.iter().collect();
+            assert_eq!(unique.len(), ids.len());
+        }
+
+        #[test]
+        fn truncate_ansi_never_exceeds_max_width(s in "\\PC{0,40}", max_width in 3usize..30) {
+            let colored = format!("\x1b[1;31m{}\x1b[0m", s);
+            let result = truncate_ansi(&colored, max_width);
+            assert!(ansi_display_width(&result) <= max_width);
+            assert_eq!(strip_ansi(&result), truncate_to_width(&s, max_width));
+        }
+
+        #[test]
+        fn truncate_to_width_never_exceeds_max_width(s in "\\PC{0,40}", max_width in 3usize..30) {
+            assert!(display_width(&truncate_to_width(&s, max_width)) <= max_width);
+        }
+
+        #[test]
+        fn truncate_middle_to_width_never_exceeds_max_width(s in "\\PC{0,40}", max_width in 0usize..30) {
+            assert!(display_width(&truncate_middle_to_width(&s, max_width)) <= max_width);
+        }
+
+        #[test]
+        fn truncate_to_width_is_identity_when_fits(s in "\\PC{0,20}") {
+            let width = display_width(&s);
+            assert_eq!(truncate_to_width(&s, width), s);
+        }
+    }
+}
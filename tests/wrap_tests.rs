@@ -0,0 +1,149 @@
+// Copyright (C) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bel7_cli::{TextWrapper, display_width, fill, wrap};
+
+#[test]
+fn test_wrap_short_text() {
+    assert_eq!(wrap("fits", 10), vec!["fits"]);
+}
+
+#[test]
+fn test_wrap_at_word_boundaries() {
+    let lines = wrap("the quick brown fox jumps over the lazy dog", 15);
+    assert_eq!(
+        lines,
+        vec!["the quick brown", "fox jumps over", "the lazy dog"]
+    );
+}
+
+#[test]
+fn test_wrap_preserves_newlines() {
+    let lines = wrap("first paragraph\n\nsecond one", 40);
+    assert_eq!(lines, vec!["first paragraph", "", "second one"]);
+}
+
+#[test]
+fn test_wrap_collapses_whitespace() {
+    assert_eq!(wrap("a   b\tc", 10), vec!["a b c"]);
+}
+
+#[test]
+fn test_wrap_breaks_long_words() {
+    let lines = wrap("see https://www.rabbitmq.com/docs/quorum-queues", 20);
+    assert_eq!(
+        lines,
+        vec!["see", "https://www.rabbitmq", ".com/docs/quorum-que", "ues"]
+    );
+}
+
+#[test]
+fn test_wrap_first_line_prefix_and_hanging_indent() {
+    let lines = TextWrapper::new()
+        .width(24)
+        .first_line_prefix("  * ")
+        .hanging_indent(4)
+        .wrap("a node with this name is already a cluster member\nretry with --force");
+    assert_eq!(
+        lines,
+        vec![
+            "  * a node with this",
+            "    name is already a",
+            "    cluster member",
+            "    retry with --force",
+        ]
+    );
+}
+
+#[test]
+fn test_wrap_wide_characters() {
+    let lines = wrap("キュー の 名前 が 長すぎます", 10);
+    for line in &lines {
+        assert!(display_width(line) <= 10, "{line:?} is too wide");
+    }
+    assert_eq!(lines.concat().replace(' ', ""), "キューの名前が長すぎます");
+}
+
+#[test]
+fn test_wrap_ignores_ansi_escapes_when_measuring() {
+    let text = "\x1b[31mfailed\x1b[39m to connect";
+    assert_eq!(wrap(text, 17), vec![text]);
+}
+
+#[test]
+fn test_wrap_splits_colored_words_between_escapes() {
+    assert_eq!(
+        wrap("\x1b[31mcrashed\x1b[39m", 3),
+        vec![
+            "\x1b[31mcra\x1b[0m",
+            "\x1b[31mshe\x1b[0m",
+            "\x1b[31md\x1b[39m",
+        ]
+    );
+}
+
+#[test]
+fn test_wrap_reopens_styles_on_continuation_lines() {
+    let lines = wrap("\x1b[1;32mall queues are running\x1b[0m", 12);
+    assert_eq!(
+        lines,
+        vec![
+            "\x1b[1;32mall queues\x1b[0m",
+            "\x1b[1;32mare running\x1b[0m",
+        ]
+    );
+}
+
+#[test]
+fn test_wrap_closes_hyperlinks_at_line_breaks() {
+    let lines = wrap(
+        "\x1b]8;;https://example.com\x1b\\docs page\x1b]8;;\x1b\\",
+        5,
+    );
+    assert_eq!(
+        lines,
+        vec![
+            "\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\",
+            "\x1b]8;;https://example.com\x1b\\page\x1b]8;;\x1b\\",
+        ]
+    );
+}
+
+#[test]
+fn test_wrap_indent_wider_than_width() {
+    let lines = TextWrapper::new().width(5).hanging_indent(7).wrap("abc de");
+    assert_eq!(lines, vec!["abc", "    d", "    e"]);
+}
+
+#[test]
+fn test_wrap_prefix_wider_than_width() {
+    let lines = TextWrapper::new()
+        .width(5)
+        .first_line_prefix("Error: ")
+        .wrap("a b");
+    for line in &lines {
+        assert!(display_width(line) <= 5, "{line:?} is too wide");
+    }
+    assert_eq!(lines, vec!["Erroa", "b"]);
+}
+
+#[test]
+fn test_fill() {
+    assert_eq!(fill("one two three", 7), "one two\nthree");
+}
+
+#[test]
+fn test_wrap_empty_text() {
+    assert!(wrap("", 10).is_empty());
+}